@@ -1,33 +1,133 @@
-use std::str::FromStr;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
+use crate::clipboard;
+use crate::collection::SavedRequest;
+use crate::components::collections::Collections;
+use crate::components::environment::Environment;
+use crate::components::export::Export;
+use crate::components::history::History;
+use crate::components::http_file::HttpFileView;
+use crate::components::import::Import;
+use crate::components::key_bindings::KeyBindings;
 use crate::components::request::Request;
 use crate::components::response::{Response, ResponseComponents};
+use crate::components::tab_bar::TabBar;
+use crate::components::toast::Toast;
+use crate::components::variables::Variables;
 use crate::components::ComponentPosition;
 use crate::components::{help_message::HelpMessage, Component, ComponentState};
-use crate::event::{Event, Events};
-use crate::http_request;
+use crate::event::{Event, Events, RequestOrigin};
+use crate::history::HistoryEntry;
+use crate::http_request::{self, ErrorKind};
+use crate::keymap::{self, Action};
+use crate::request_data::RequestData;
+use crate::template;
+use crate::ui::centered_rect;
 
 use anyhow::Result;
+use chrono::{DateTime, Local};
 use termion::event::Key;
+use tokio::task::JoinHandle;
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout},
     Frame,
 };
 
+const TAB_TITLE_LENGTH: usize = 24;
+
 pub struct App {
     pub events: Events,
     help_message: HelpMessage,
+    environment: Environment,
+    tabs: Vec<Tab>,
+    current_tab: usize,
+    next_tab_id: usize,
+    next_request_id: usize,
+    tab_bar: TabBar,
+    history: History,
+    collections: Collections,
+    variables: Variables,
+    import: Import,
+    export: Export,
+    http_file: HttpFileView,
+    key_bindings: KeyBindings,
+    toast: Toast,
+}
+
+// Each tab keeps its own request, where it came from, response and in-flight request;
+// responses are routed back to the tab that sent them by `id`, which stays stable when other
+// tabs are closed.
+struct Tab {
+    id: usize,
     request: Request,
+    origin: RequestOrigin,
     response: Response,
+    in_flight_request: Option<InFlightRequest>,
+}
+
+impl Tab {
+    fn new(id: usize) -> Self {
+        Self {
+            id,
+            request: Request::default(),
+            origin: RequestOrigin::Other,
+            response: Response::default(),
+            in_flight_request: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        let data = self.request.get_data();
+        let path = data
+            .url
+            .split_once("://")
+            .map_or(data.url.as_str(), |(_, rest)| rest);
+        let path = match path.chars().count() {
+            n if n > TAB_TITLE_LENGTH => format!(
+                "{}…",
+                path.chars().take(TAB_TITLE_LENGTH).collect::<String>()
+            ),
+            0 => "new".to_string(),
+            _ => path.to_string(),
+        };
+        let loading = if self.in_flight_request.is_some() {
+            " …"
+        } else {
+            ""
+        };
+        format!("{} {}{}", data.method, path, loading)
+    }
+}
+
+// `id` tells a response apart from one queued by an earlier request that was aborted or
+// replaced after it had already finished.
+struct InFlightRequest {
+    id: usize,
+    handle: JoinHandle<()>,
+    sent_at: DateTime<Local>,
+    saved: SavedRequest,
 }
 
 impl Default for App {
     fn default() -> Self {
         App {
             help_message: HelpMessage {},
-            request: Request::default(),
-            response: Response::default(),
+            environment: Environment::new(),
+            tabs: vec![Tab::new(0)],
+            current_tab: 0,
+            next_tab_id: 1,
+            next_request_id: 0,
+            tab_bar: TabBar::default(),
+            history: History::default(),
+            collections: Collections::default(),
+            variables: Variables::default(),
+            import: Import::default(),
+            export: Export::default(),
+            http_file: HttpFileView::default(),
+            key_bindings: KeyBindings::default(),
+            toast: Toast::default(),
             events: Events::new(),
         }
     }
@@ -35,95 +135,440 @@ impl Default for App {
 
 impl App {
     pub fn render<B: Backend>(&mut self, f: &mut Frame<'_, B>) -> Result<()> {
-        let (help_message_area, request_area, response_area) = {
+        let (opened, http_request) = match &self.tabs[self.current_tab].origin {
+            RequestOrigin::Collection(path, _) => (Some(path.clone()), None),
+            RequestOrigin::HttpFile(path, index) => (None, Some((path.clone(), *index))),
+            RequestOrigin::Other => (None, None),
+        };
+        self.collections.set_opened(opened);
+        self.http_file.set_opened(http_request);
+
+        let main_area = if self.collections.is_visible() {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .margin(2)
+                .constraints([Constraint::Length(36), Constraint::Min(1)].as_ref())
+                .split(f.size());
+            self.collections.render(f, chunks[0])?;
+            chunks[1]
+        } else {
+            f.size()
+        };
+
+        let (help_message_area, tab_bar_area, request_area, response_area) = {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(2)
                 .constraints(
                     [
                         Constraint::Length(3),
+                        Constraint::Length(1),
                         Constraint::Percentage(40),
                         Constraint::Min(1),
                     ]
                     .as_ref(),
                 )
-                .split(f.size());
-            (chunks[0], chunks[1], chunks[2])
+                .split(main_area);
+            (chunks[0], chunks[1], chunks[2], chunks[3])
+        };
+        let (help_message_area, environment_area) = {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(1), Constraint::Length(40)].as_ref())
+                .split(help_message_area);
+            (chunks[0], chunks[1])
         };
         self.help_message.render(f, help_message_area)?;
-        self.request.render(f, request_area)?;
-        self.response.render(f, response_area)?;
+        self.environment.render(f, environment_area)?;
+        self.tab_bar.set_tabs(
+            self.tabs.iter().map(|tab| tab.title()).collect(),
+            self.current_tab,
+        );
+        self.tab_bar.render(f, tab_bar_area)?;
+        let tab = &mut self.tabs[self.current_tab];
+        tab.request.render(f, request_area)?;
+        tab.response.render(f, response_area)?;
+        tab.request.render_popup(f)?;
+        if self.history.is_focused() {
+            self.history.render(f, centered_rect(90, 80, f.size()))?;
+        }
+        if self.variables.is_focused() {
+            self.variables.render(f, centered_rect(70, 60, f.size()))?;
+        }
+        if self.import.is_focused() {
+            self.import.render(f, centered_rect(80, 40, f.size()))?;
+        }
+        if self.export.is_focused() {
+            self.export.render(f, centered_rect(80, 70, f.size()))?;
+        }
+        if self.http_file.is_focused() {
+            self.http_file.render(f, centered_rect(70, 60, f.size()))?;
+        }
+        if self.key_bindings.is_focused() {
+            self.key_bindings
+                .render(f, centered_rect(70, 80, f.size()))?;
+        }
+        self.toast.render(f, f.size())?;
 
         Ok(())
     }
 
     pub fn key_handle(&mut self, k: Key) -> Result<()> {
-        if self.request.is_focused() {
-            self.request.key_handle(k, self.events.sender())?;
-        } else if self.response.is_focused() {
-            self.response.key_handle(k, self.events.sender())?;
+        let tab = &mut self.tabs[self.current_tab];
+        // Overlays keep Esc for closing themselves.
+        let pane_focused = tab.request.is_focused() || tab.response.is_focused();
+        if tab.in_flight_request.is_some()
+            && keymap::get().is(Action::CancelRequest, k)
+            && (k != Key::Esc || pane_focused)
+        {
+            self.cancel_request();
+            return Ok(());
+        }
+
+        if tab.request.is_focused() {
+            tab.request.key_handle(k, self.events.sender())?;
+        } else if tab.response.is_focused() {
+            tab.response.key_handle(k, self.events.sender())?;
+        } else if self.history.is_focused() {
+            self.history.key_handle(k, self.events.sender())?;
+        } else if self.collections.is_focused() {
+            self.collections.key_handle(k, self.events.sender())?;
+        } else if self.variables.is_focused() {
+            self.variables.key_handle(k, self.events.sender())?;
+        } else if self.import.is_focused() {
+            self.import.key_handle(k, self.events.sender())?;
+        } else if self.export.is_focused() {
+            self.export.key_handle(k, self.events.sender())?;
+        } else if self.http_file.is_focused() {
+            self.http_file.key_handle(k, self.events.sender())?;
+        } else if self.key_bindings.is_focused() {
+            self.key_bindings.key_handle(k, self.events.sender())?;
         }
 
         Ok(())
     }
 
     pub fn change_focus(&mut self, position: ComponentPosition) {
-        self.response.set_state(ComponentState::UnFocused);
-        self.request.unfocused();
+        for tab in &mut self.tabs {
+            tab.response.set_state(ComponentState::UnFocused);
+            tab.request.unfocused();
+        }
+        self.history.set_state(ComponentState::UnFocused);
+        self.collections.set_state(ComponentState::UnFocused);
+        self.variables.set_state(ComponentState::UnFocused);
+        self.import.set_state(ComponentState::UnFocused);
+        self.export.set_state(ComponentState::UnFocused);
+        self.http_file.set_state(ComponentState::UnFocused);
+        self.key_bindings.set_state(ComponentState::UnFocused);
+        let tab = &mut self.tabs[self.current_tab];
         match position {
             ComponentPosition::RequestMethod => {
-                self.request.set_state(ComponentState::Focused);
-                self.request.method.set_state(ComponentState::Focused);
+                tab.request.set_state(ComponentState::Focused);
+                tab.request.method.set_state(ComponentState::Focused);
             }
             ComponentPosition::RequestUrl => {
-                self.request.set_state(ComponentState::Focused);
-                self.request.url.set_state(ComponentState::Focused);
+                tab.request.set_state(ComponentState::Focused);
+                tab.request.url.set_state(ComponentState::Focused);
             }
             ComponentPosition::RequestQuery => {
-                self.request.set_state(ComponentState::Focused);
-                self.request.query.set_state(ComponentState::Focused);
+                tab.request.set_state(ComponentState::Focused);
+                tab.request.query.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::RequestHeader => {
+                tab.request.set_state(ComponentState::Focused);
+                tab.request.header.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::RequestAuth => {
+                tab.request.set_state(ComponentState::Focused);
+                tab.request.auth.set_state(ComponentState::Focused);
             }
             ComponentPosition::RequestBody => {
-                self.request.set_state(ComponentState::Focused);
-                self.request.body.set_state(ComponentState::Focused);
+                tab.request.set_state(ComponentState::Focused);
+                tab.request.body.set_state(ComponentState::Focused);
             }
             ComponentPosition::Response(response_component) => {
-                self.response.unfocused();
-                self.response.set_state(ComponentState::Focused);
+                tab.response.unfocused();
+                tab.response.set_state(ComponentState::Focused);
                 match response_component {
                     ResponseComponents::Body => {
-                        self.response.body.set_state(ComponentState::Focused);
+                        tab.response.body.set_state(ComponentState::Focused);
                     }
                     ResponseComponents::Header => {
-                        self.response.header.set_state(ComponentState::Focused);
+                        tab.response.header.set_state(ComponentState::Focused);
+                    }
+                    ResponseComponents::Tests => {
+                        tab.response.tests.set_state(ComponentState::Focused);
                     }
                 }
             }
+            ComponentPosition::History => {
+                self.history.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::Collections => {
+                self.collections.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::Variables => {
+                self.environment.reload();
+                self.variables.set_environment(self.environment.variables());
+                self.variables.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::Import => {
+                self.import.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::Export => {
+                let data = tab.request.get_data();
+                let variables = self.template_variables();
+                self.export
+                    .set_data(template::render_request(&data, &variables));
+                self.export.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::HttpFile => {
+                self.http_file.set_state(ComponentState::Focused);
+            }
+            ComponentPosition::KeyBindings => {
+                self.key_bindings.set_state(ComponentState::Focused);
+            }
         }
     }
 
+    fn tab(&mut self) -> &mut Tab {
+        &mut self.tabs[self.current_tab]
+    }
+
+    pub fn new_tab_handle(&mut self) {
+        self.open_tab(Tab::new(self.next_tab_id));
+    }
+
+    pub fn duplicate_tab_handle(&mut self) {
+        let data = self.tab().request.get_data();
+        let mut tab = Tab::new(self.next_tab_id);
+        tab.request.set_data(&data);
+        self.open_tab(tab);
+    }
+
+    fn open_tab(&mut self, tab: Tab) {
+        self.next_tab_id += 1;
+        self.current_tab += 1;
+        self.tabs.insert(self.current_tab, tab);
+        self.change_focus(ComponentPosition::RequestUrl);
+    }
+
+    pub fn close_tab_handle(&mut self) {
+        let tab = self.tabs.remove(self.current_tab);
+        if let Some(in_flight) = tab.in_flight_request {
+            in_flight.handle.abort();
+        }
+        if self.tabs.is_empty() {
+            self.tabs.push(Tab::new(self.next_tab_id));
+            self.next_tab_id += 1;
+        }
+        self.current_tab = self.current_tab.min(self.tabs.len() - 1);
+        self.change_focus(ComponentPosition::RequestUrl);
+    }
+
+    pub fn switch_tab_handle(&mut self, offset: isize) {
+        let len = self.tabs.len() as isize;
+        self.current_tab = (self.current_tab as isize + offset).rem_euclid(len) as usize;
+        self.change_focus(ComponentPosition::RequestUrl);
+    }
+
     pub fn set_query_handle(&mut self, query: Vec<char>) {
-        self.request.query.set_data(&query);
-        self.request.url.set_query(&query);
+        self.tab().request.query.set_data(&query);
+        self.tab().request.url.set_query(&query);
     }
 
-    pub async fn request_handle(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        match http_request::request(
-            &reqwest::Request::new(
-                self.request.get_method(),
-                reqwest::Url::from_str(&self.request.get_url())?,
-            ),
-            self.request.get_body(),
-        )
-        .await?
-        {
-            Some(resp) => self.events.sender().send(Event::Response(resp)),
-            None => {}
+    pub fn request_handle(&mut self) {
+        let mut saved = match &self.tab().origin {
+            RequestOrigin::Collection(_, saved) => (**saved).clone(),
+            _ => SavedRequest::default(),
+        };
+        saved.request = self.tab().request.get_data();
+        self.send(saved);
+    }
+
+    pub fn resend_handle(&mut self, data: RequestData) {
+        self.change_focus(ComponentPosition::Response(ResponseComponents::Body));
+        self.send(SavedRequest {
+            request: data,
+            ..SavedRequest::default()
+        });
+    }
+
+    pub fn load_request_handle(&mut self, data: RequestData, origin: RequestOrigin) {
+        self.tab().request.set_data(&data);
+        self.tab().origin = origin;
+        self.change_focus(ComponentPosition::RequestUrl);
+    }
+
+    pub fn save_request_handle(&mut self, path: Option<PathBuf>) {
+        let tab = &mut self.tabs[self.current_tab];
+        let data = tab.request.get_data();
+        self.collections.save(path, &mut tab.origin, data);
+    }
+
+    pub fn saved_request_renamed_handle(&mut self, from: PathBuf, to: PathBuf) {
+        for tab in &mut self.tabs {
+            if let RequestOrigin::Collection(path, _) = &mut tab.origin {
+                if *path == from {
+                    *path = to.clone();
+                }
+            }
+        }
+    }
+
+    pub fn saved_request_deleted_handle(&mut self, deleted: PathBuf) {
+        for tab in &mut self.tabs {
+            if matches!(&tab.origin, RequestOrigin::Collection(path, _) if *path == deleted) {
+                tab.origin = RequestOrigin::Other;
+            }
+        }
+    }
+
+    pub fn open_http_file(&mut self, path: &Path) {
+        self.http_file.open(path);
+        self.change_focus(ComponentPosition::HttpFile);
+    }
+
+    pub fn write_http_file_handle(&mut self) {
+        let tab = &self.tabs[self.current_tab];
+        self.http_file.write(&tab.origin, tab.request.get_data());
+    }
+
+    pub fn switch_environment_handle(&mut self) {
+        self.environment.switch_next();
+    }
+
+    fn send(&mut self, saved: SavedRequest) {
+        if let Some(in_flight) = self.tab().in_flight_request.take() {
+            in_flight.handle.abort();
+        }
+
+        // History keeps the `{{var}}` form so restoring a request keeps its templates and
+        // secrets from environments are not recorded.
+        let variables = self.template_variables();
+        let req = template::render_request(&saved.request, &variables);
+
+        let tx = self.events.owned_sender();
+        let tab_id = self.tab().id;
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        let handle = tokio::spawn(async move {
+            let event = match http_request::request(&req).await {
+                Ok(resp) => Event::Response(tab_id, id, resp),
+                Err(e) => Event::RequestError(tab_id, id, e),
+            };
+            // The receiver only goes away when the app is quitting, so there is no one to tell.
+            let _ = tx.send(event);
+        });
+        self.tab().in_flight_request = Some(InFlightRequest {
+            id,
+            handle,
+            sent_at: Local::now(),
+            saved,
+        });
+        self.tab().response.set_loading();
+    }
+
+    pub fn copy_handle(&mut self, text: String) {
+        match clipboard::copy(&text) {
+            Ok(()) => self.toast.show(format!("Copied {} bytes", text.len())),
+            Err(err) => self.toast.show_error(format!("Copy failed: {}", err)),
         }
-        Ok(())
     }
 
-    pub fn response_handle(&mut self, resp: http_request::Response) {
-        self.response.set_data(resp);
+    pub fn notify_handle(&mut self, message: Result<String, String>) {
+        match message {
+            Ok(message) => self.toast.show(message),
+            Err(message) => self.toast.show_error(message),
+        }
+    }
+
+    fn template_variables(&mut self) -> HashMap<String, String> {
+        self.environment.reload();
+        let origin = &self.tabs[self.current_tab].origin;
+        let mut variables = self
+            .http_file
+            .variables(origin, self.environment.variables());
+        variables.extend(
+            self.variables
+                .captured()
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+        variables
+    }
+
+    pub fn cancel_request(&mut self) {
+        if let Some(in_flight) = self.tab().in_flight_request.take() {
+            in_flight.handle.abort();
+            let err = http_request::Error::new(
+                ErrorKind::Cancelled,
+                "The request was cancelled before a response arrived.",
+            );
+            self.record_history(HistoryEntry::from_error(
+                in_flight.sent_at,
+                in_flight.saved.request,
+                &err,
+            ));
+            self.tab().response.set_error(err);
+        }
+    }
+
+    fn tab_by_id(&mut self, id: usize) -> Option<&mut Tab> {
+        self.tabs.iter_mut().find(|tab| tab.id == id)
+    }
+
+    fn take_in_flight(&mut self, tab_id: usize, request_id: usize) -> Option<InFlightRequest> {
+        let tab = self.tab_by_id(tab_id)?;
+        if tab.in_flight_request.as_ref()?.id != request_id {
+            return None;
+        }
+        tab.in_flight_request.take()
+    }
+
+    pub fn response_handle(
+        &mut self,
+        tab_id: usize,
+        request_id: usize,
+        resp: http_request::Response,
+    ) {
+        if let Some(in_flight) = self.take_in_flight(tab_id, request_id) {
+            self.record_history(HistoryEntry::from_response(
+                in_flight.sent_at,
+                in_flight.saved.request,
+                &resp,
+            ));
+            if !in_flight.saved.captures.is_empty() {
+                self.variables.capture(&in_flight.saved.captures, &resp);
+            }
+            if let Some(tab) = self.tab_by_id(tab_id) {
+                tab.response.set_data(resp, &in_flight.saved.assertions);
+            }
+        }
+    }
+
+    pub fn request_error_handle(
+        &mut self,
+        tab_id: usize,
+        request_id: usize,
+        err: http_request::Error,
+    ) {
+        if let Some(in_flight) = self.take_in_flight(tab_id, request_id) {
+            self.record_history(HistoryEntry::from_error(
+                in_flight.sent_at,
+                in_flight.saved.request,
+                &err,
+            ));
+            if let Some(tab) = self.tab_by_id(tab_id) {
+                tab.response.set_error(err);
+            }
+        }
+    }
+
+    fn record_history(&mut self, entry: HistoryEntry) {
+        if let Err(err) = self.history.append(entry) {
+            self.toast
+                .show_error(format!("Could not record history: {}", err));
+        }
     }
 }
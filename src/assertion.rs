@@ -0,0 +1,131 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::http_request;
+use crate::json_path;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Assertion {
+    StatusEquals {
+        status: u16,
+    },
+    StatusInRange {
+        min: u16,
+        max: u16,
+    },
+    HeaderPresent {
+        header: String,
+    },
+    HeaderMatches {
+        header: String,
+        pattern: String,
+    },
+    JsonPathEquals {
+        path: String,
+        value: serde_json::Value,
+    },
+    JsonPathExists {
+        path: String,
+    },
+    BodyContains {
+        text: String,
+    },
+    ResponseTimeBelow {
+        ms: u64,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct AssertionResult {
+    pub description: String,
+    pub passed: bool,
+    pub message: Option<String>,
+}
+
+impl Assertion {
+    pub fn description(&self) -> String {
+        match self {
+            Assertion::StatusEquals { status } => format!("status == {}", status),
+            Assertion::StatusInRange { min, max } => format!("status in {}..={}", min, max),
+            Assertion::HeaderPresent { header } => format!("header {} present", header),
+            Assertion::HeaderMatches { header, pattern } => {
+                format!("header {} matches /{}/", header, pattern)
+            }
+            Assertion::JsonPathEquals { path, value } => format!("{} == {}", path, value),
+            Assertion::JsonPathExists { path } => format!("{} exists", path),
+            Assertion::BodyContains { text } => format!("body contains {:?}", text),
+            Assertion::ResponseTimeBelow { ms } => format!("response time < {}ms", ms),
+        }
+    }
+
+    pub fn evaluate(&self, resp: &http_request::Response) -> AssertionResult {
+        let result = self.check(resp);
+        AssertionResult {
+            description: self.description(),
+            passed: result.is_ok(),
+            message: result.err(),
+        }
+    }
+
+    fn check(&self, resp: &http_request::Response) -> Result<(), String> {
+        match self {
+            Assertion::StatusEquals { status } => expect(
+                resp.status.as_u16() == *status,
+                format!("got {}", resp.status.as_u16()),
+            ),
+            Assertion::StatusInRange { min, max } => expect(
+                (*min..=*max).contains(&resp.status.as_u16()),
+                format!("got {}", resp.status.as_u16()),
+            ),
+            Assertion::HeaderPresent { header } => find_header(resp, header).map(|_| ()),
+            Assertion::HeaderMatches { header, pattern } => {
+                let value = find_header(resp, header)?;
+                let regex = Regex::new(pattern).map_err(|e| e.to_string())?;
+                expect(regex.is_match(value), format!("got {:?}", value))
+            }
+            Assertion::JsonPathEquals { path, value } => {
+                let actual = select_json(resp, path)?;
+                expect(&actual == value, format!("got {}", actual))
+            }
+            Assertion::JsonPathExists { path } => select_json(resp, path).map(|_| ()),
+            Assertion::BodyContains { text } => expect(
+                String::from_utf8_lossy(&resp.raw_body).contains(text.as_str()),
+                "not found in body".to_string(),
+            ),
+            Assertion::ResponseTimeBelow { ms } => expect(
+                resp.delay.as_millis() < *ms as u128,
+                format!("took {}ms", resp.delay.as_millis()),
+            ),
+        }
+    }
+}
+
+pub fn evaluate_all(
+    assertions: &[Assertion],
+    resp: &http_request::Response,
+) -> Vec<AssertionResult> {
+    assertions
+        .iter()
+        .map(|assertion| assertion.evaluate(resp))
+        .collect()
+}
+
+fn expect(passed: bool, message: String) -> Result<(), String> {
+    if passed {
+        Ok(())
+    } else {
+        Err(message)
+    }
+}
+
+fn find_header<'a>(resp: &'a http_request::Response, header: &str) -> Result<&'a str, String> {
+    resp.header_value(header)
+        .ok_or_else(|| format!("no {} header", header))
+}
+
+fn select_json(resp: &http_request::Response, path: &str) -> Result<serde_json::Value, String> {
+    json_path::select(&resp.json_body()?, path)?
+        .cloned()
+        .ok_or_else(|| format!("no value at {}", path))
+}
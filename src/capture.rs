@@ -0,0 +1,46 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::http_request;
+use crate::json_path;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Capture {
+    pub name: String,
+    #[serde(flatten)]
+    pub source: CaptureSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "from", rename_all = "snake_case")]
+pub enum CaptureSource {
+    Json { path: String },
+    Header { header: String },
+    Regex { pattern: String },
+}
+
+impl Capture {
+    pub fn extract(&self, resp: &http_request::Response) -> Result<String, String> {
+        match &self.source {
+            CaptureSource::Json { path } => json_path::select(&resp.json_body()?, path)?
+                .map(json_path::to_plain_string)
+                .ok_or_else(|| format!("no value at {}", path)),
+            CaptureSource::Header { header } => resp
+                .header_value(header)
+                .map(str::to_string)
+                .ok_or_else(|| format!("no {} header", header)),
+            CaptureSource::Regex { pattern } => {
+                let regex = Regex::new(pattern).map_err(|e| e.to_string())?;
+                let body = String::from_utf8_lossy(&resp.raw_body);
+                let captures = regex
+                    .captures(&body)
+                    .ok_or_else(|| format!("no match for {}", pattern))?;
+                Ok(captures
+                    .get(1)
+                    .or_else(|| captures.get(0))
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default())
+            }
+        }
+    }
+}
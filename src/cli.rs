@@ -1,5 +1,143 @@
+use std::path::PathBuf;
+
 use structopt::StructOpt;
 
+use crate::curl::FORM_CONTENT_TYPE;
+use crate::request_data::{AuthData, HeaderData, RequestData};
+
 #[derive(Debug, StructOpt)]
 #[structopt(name = "curl_tui", about = "curl tui")]
-pub struct Cli {}
+pub struct Cli {
+    #[structopt(subcommand)]
+    pub command: Option<Command>,
+    /// URL to open in the request editor
+    pub url: Option<String>,
+    /// Request method
+    #[structopt(short = "X", long = "request")]
+    pub method: Option<String>,
+    /// Request header, e.g. "Accept: application/json"
+    #[structopt(short = "H", long = "header", number_of_values = 1)]
+    pub headers: Vec<String>,
+    /// Request body; multiple values are joined with "&"
+    #[structopt(short = "d", long = "data", alias = "data-raw", number_of_values = 1)]
+    pub data: Vec<String>,
+    /// Basic auth credentials as user:password
+    #[structopt(short = "u", long = "user")]
+    pub user: Option<String>,
+    /// Skip TLS certificate verification
+    #[structopt(short = "k", long = "insecure")]
+    pub insecure: bool,
+    /// Open a .http / REST Client file to pick requests from
+    #[structopt(long)]
+    pub http_file: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    /// Send a saved request, or every request in a collection folder, without the TUI
+    ///
+    /// A folder's requests run in file name order, subfolders first, so values captured by one
+    /// request are available to the ones after it. Prefix names with numbers such as
+    /// "01 login" to control the order.
+    Run {
+        /// Request or folder path relative to the collections directory
+        target: PathBuf,
+        /// Environment to use instead of the active one
+        #[structopt(long)]
+        env: Option<String>,
+    },
+    /// Import a HAR file into the request history, or into a collection folder
+    ImportHar {
+        file: PathBuf,
+        /// Collection folder to save the requests into instead of the history
+        #[structopt(long)]
+        collection: Option<PathBuf>,
+    },
+    /// Import a Postman v2.1 collection into saved requests and environments
+    ImportPostman {
+        file: PathBuf,
+        /// Postman environment file to import alongside the collection
+        #[structopt(long = "environment", number_of_values = 1)]
+        environments: Vec<PathBuf>,
+        /// Collection folder to save the requests into instead of one named after the collection
+        #[structopt(long)]
+        collection: Option<PathBuf>,
+    },
+    /// Generate saved requests from an OpenAPI 3 YAML or JSON document
+    ImportOpenapi {
+        file: PathBuf,
+        /// Collection folder to save the requests into instead of one named after the API
+        #[structopt(long)]
+        collection: Option<PathBuf>,
+    },
+    /// Export the request history as a HAR file
+    ExportHar {
+        file: PathBuf,
+        /// Only export the most recent entries
+        #[structopt(long)]
+        limit: Option<usize>,
+    },
+}
+
+impl Cli {
+    pub fn request_data(&self) -> Option<RequestData> {
+        if self.url.is_none()
+            && self.method.is_none()
+            && self.headers.is_empty()
+            && self.data.is_empty()
+            && self.user.is_none()
+            && !self.insecure
+        {
+            return None;
+        }
+
+        let method = match &self.method {
+            Some(method) => method.clone(),
+            None if !self.data.is_empty() => "POST".to_string(),
+            None => "GET".to_string(),
+        };
+        let mut headers = self
+            .headers
+            .iter()
+            .map(|header| {
+                let (name, value) = header.split_once(':').unwrap_or((header, ""));
+                HeaderData {
+                    name: name.trim().to_string(),
+                    value: value.trim().to_string(),
+                    enabled: true,
+                }
+            })
+            .collect::<Vec<HeaderData>>();
+        // Like curl, data without an explicit Content-Type is sent as a form.
+        if !self.data.is_empty()
+            && !headers
+                .iter()
+                .any(|h| h.name.eq_ignore_ascii_case("Content-Type"))
+        {
+            headers.push(HeaderData {
+                name: "Content-Type".to_string(),
+                value: FORM_CONTENT_TYPE.to_string(),
+                enabled: true,
+            });
+        }
+        let auth = match &self.user {
+            Some(user) => {
+                let (username, password) = user.split_once(':').unwrap_or((user, ""));
+                AuthData::Basic {
+                    username: username.to_string(),
+                    password: password.to_string(),
+                }
+            }
+            None => AuthData::None,
+        };
+
+        Some(RequestData {
+            method,
+            url: self.url.clone().unwrap_or_default(),
+            headers,
+            auth,
+            body: self.data.join("&"),
+            insecure: self.insecure,
+        })
+    }
+}
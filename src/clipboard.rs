@@ -0,0 +1,15 @@
+use std::env;
+use std::io::{self, Write};
+
+// OSC 52 asks the terminal itself to set the clipboard, so it also works over SSH.
+pub fn copy(text: &str) -> io::Result<()> {
+    let encoded = base64::encode(text);
+    let sequence = if env::var_os("TMUX").is_some() {
+        format!("\x1bPtmux;\x1b\x1b]52;c;{}\x07\x1b\\", encoded)
+    } else {
+        format!("\x1b]52;c;{}\x07", encoded)
+    };
+    let mut stdout = io::stdout();
+    stdout.write_all(sequence.as_bytes())?;
+    stdout.flush()
+}
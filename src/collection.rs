@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::assertion::Assertion;
+use crate::capture::Capture;
+use crate::request_data::RequestData;
+use crate::workspace;
+
+const COLLECTIONS_DIR: &str = "collections";
+const EXTENSION: &str = "toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedRequest {
+    #[serde(flatten)]
+    pub request: RequestData,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub captures: Vec<Capture>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assertions: Vec<Assertion>,
+}
+
+pub struct CollectionItem {
+    pub path: PathBuf,
+    pub name: String,
+    pub depth: usize,
+    pub is_dir: bool,
+}
+
+pub struct CollectionStore {
+    root: PathBuf,
+}
+
+impl Default for CollectionStore {
+    fn default() -> Self {
+        Self {
+            root: workspace::dir().join(COLLECTIONS_DIR),
+        }
+    }
+}
+
+impl CollectionStore {
+    pub fn list(&self) -> Result<Vec<CollectionItem>> {
+        let mut items = vec![];
+        if self.root.is_dir() {
+            self.walk(Path::new(""), 0, &mut items)?;
+        }
+        Ok(items)
+    }
+
+    fn walk(&self, rel: &Path, depth: usize, items: &mut Vec<CollectionItem>) -> Result<()> {
+        let mut entries = fs::read_dir(self.root.join(rel))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| (entry.path().is_dir(), entry.file_name()))
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        for (is_dir, file_name) in entries {
+            let path = rel.join(&file_name);
+            if is_dir {
+                items.push(CollectionItem {
+                    name: file_name.to_string_lossy().to_string(),
+                    path: path.clone(),
+                    depth,
+                    is_dir,
+                });
+                self.walk(&path, depth + 1, items)?;
+            } else if path.extension().is_some_and(|ext| ext == EXTENSION) {
+                items.push(CollectionItem {
+                    name: path
+                        .file_stem()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_default(),
+                    path: path.with_extension(""),
+                    depth,
+                    is_dir,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn checked_path(&self, rel: &Path) -> Result<PathBuf> {
+        let name = rel.to_string_lossy();
+        if matches!(name.rsplit(['/', '\\']).next(), None | Some("" | "."))
+            || rel.is_absolute()
+            || rel.components().any(|c| c.as_os_str() == "..")
+        {
+            return Err(anyhow!("invalid request path: {}", rel.display()));
+        }
+        Ok(self.root.join(rel))
+    }
+
+    fn file_path(&self, rel: &Path) -> Result<PathBuf> {
+        self.checked_path(rel)?;
+        let mut file_name = rel.as_os_str().to_owned();
+        file_name.push(".");
+        file_name.push(EXTENSION);
+        Ok(self.root.join(file_name))
+    }
+
+    pub fn exists(&self, rel: &Path) -> bool {
+        self.file_path(rel).is_ok_and(|path| path.exists())
+    }
+
+    pub fn resolve(&self, rel: &Path) -> Result<Vec<PathBuf>> {
+        let rel = rel
+            .components()
+            .filter(|c| *c != Component::CurDir)
+            .collect::<PathBuf>();
+        let rel = rel.as_path();
+        if self.exists(rel) {
+            return Ok(vec![rel.to_path_buf()]);
+        }
+        if !self.root.join(rel).is_dir() {
+            return Err(anyhow!("no request or collection named {}", rel.display()));
+        }
+        Ok(self
+            .list()?
+            .into_iter()
+            .filter(|item| !item.is_dir && item.path.starts_with(rel))
+            .map(|item| item.path)
+            .collect())
+    }
+
+    pub fn load(&self, rel: &Path) -> Result<SavedRequest> {
+        Ok(toml::from_str(&fs::read_to_string(self.file_path(rel)?)?)?)
+    }
+
+    pub fn save(&self, rel: &Path, saved: &SavedRequest) -> Result<()> {
+        let path = self.file_path(rel)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Converting through `toml::Value` lets the serializer put tables after plain values.
+        fs::write(
+            path,
+            toml::to_string_pretty(&toml::Value::try_from(saved)?)?,
+        )?;
+        Ok(())
+    }
+
+    pub fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let to_path = self.file_path(to)?;
+        if to_path.exists() {
+            return Err(anyhow!("{} already exists", to.display()));
+        }
+        if let Some(dir) = to_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::rename(self.file_path(from)?, to_path)?;
+        Ok(())
+    }
+
+    pub fn duplicate(&self, rel: &Path) -> Result<PathBuf> {
+        let name = rel
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut copy_path = rel.with_file_name(format!("{} copy", name));
+        let mut n = 2;
+        while self.exists(&copy_path) {
+            copy_path = rel.with_file_name(format!("{} copy {}", name, n));
+            n += 1;
+        }
+        fs::copy(self.file_path(rel)?, self.file_path(&copy_path)?)?;
+        Ok(copy_path)
+    }
+
+    // Picks a path that is neither used earlier in the same import nor already saved, so importing
+    // again never overwrites saved requests. The flag tells whether a saved request was in the way.
+    pub fn unique_path(
+        &self,
+        dir: &Path,
+        name: &str,
+        used: &mut HashSet<PathBuf>,
+    ) -> (PathBuf, bool) {
+        let wanted = dir.join(file_name(name));
+        let collided = !used.contains(&wanted) && self.exists(&wanted);
+        let mut path = wanted;
+        let mut n = 1;
+        while used.contains(&path) || self.exists(&path) {
+            n += 1;
+            path = dir.join(format!("{} ({})", file_name(name), n));
+        }
+        used.insert(path.clone());
+        (path, collided)
+    }
+
+    pub fn delete(&self, rel: &Path) -> Result<()> {
+        fs::remove_file(self.file_path(rel)?)?;
+        Ok(())
+    }
+
+    pub fn rename_folder(&self, from: &Path, to: &Path) -> Result<()> {
+        let to_path = self.checked_path(to)?;
+        if to_path.exists() || self.exists(to) {
+            return Err(anyhow!("{} already exists", to.display()));
+        }
+        if let Some(dir) = to_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::rename(self.checked_path(from)?, to_path)?;
+        Ok(())
+    }
+
+    pub fn delete_folder(&self, rel: &Path) -> Result<()> {
+        fs::remove_dir_all(self.checked_path(rel)?)?;
+        Ok(())
+    }
+}
+
+// Turns an imported name into a single path component.
+pub fn file_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| if "/\\:".contains(c) { '_' } else { c })
+        .collect::<String>();
+    let name = name.trim().trim_start_matches('.');
+    if name.is_empty() {
+        "untitled".to_string()
+    } else {
+        name.to_string()
+    }
+}
@@ -0,0 +1,437 @@
+use std::path::{Path, PathBuf};
+
+use crate::collection::{CollectionItem, CollectionStore, SavedRequest};
+use crate::components::{line_input::LineInput, Component, ComponentPosition, ComponentState};
+use crate::event::{Event, EventSender, RequestOrigin};
+use crate::keymap::{self, Action};
+use crate::request_data::RequestData;
+use crate::ui::default_key_handle;
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Prompt {
+    SaveAs,
+    Rename,
+    Delete,
+    DeleteFolder,
+    Overwrite,
+}
+
+impl Prompt {
+    fn label(&self) -> String {
+        let confirm = keymap::get().label(Action::Confirm);
+        match *self {
+            Prompt::SaveAs => "Save as: ".to_string(),
+            Prompt::Rename => "Rename to: ".to_string(),
+            Prompt::Delete => format!("Delete? ({}/n) ", confirm),
+            Prompt::DeleteFolder => {
+                format!(
+                    "Delete the folder and every request in it? ({}/n) ",
+                    confirm
+                )
+            }
+            Prompt::Overwrite => format!("Overwrite? ({}/n) ", confirm),
+        }
+    }
+}
+
+pub struct Collections {
+    store: CollectionStore,
+    items: Vec<CollectionItem>,
+    selected: usize,
+    // The saved request open in the current tab, marked in the list.
+    opened: Option<PathBuf>,
+    prompt: Option<Prompt>,
+    input: LineInput,
+    message: Option<String>,
+    visible: bool,
+    state: ComponentState,
+}
+
+impl Default for Collections {
+    fn default() -> Self {
+        let mut collections = Self {
+            store: CollectionStore::default(),
+            items: vec![],
+            selected: 0,
+            opened: None,
+            prompt: None,
+            input: LineInput::default(),
+            message: None,
+            visible: false,
+            state: ComponentState::UnFocused,
+        };
+        collections.refresh();
+        collections
+    }
+}
+
+impl Collections {
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn set_opened(&mut self, opened: Option<PathBuf>) {
+        self.opened = opened;
+    }
+
+    fn refresh(&mut self) {
+        match self.store.list() {
+            Ok(items) => self.items = items,
+            Err(err) => self.message = Some(err.to_string()),
+        }
+        if self.selected >= self.items.len() {
+            self.selected = self.items.len().saturating_sub(1);
+        }
+    }
+
+    fn selected_item(&self) -> Option<&CollectionItem> {
+        self.items.get(self.selected)
+    }
+
+    fn select(&mut self, path: &Path) {
+        if let Some(i) = self.items.iter().position(|item| item.path == path) {
+            self.selected = i;
+        }
+    }
+
+    fn requests_in(&self, folder: &Path) -> Vec<PathBuf> {
+        self.items
+            .iter()
+            .filter(|item| !item.is_dir && item.path.starts_with(folder))
+            .map(|item| item.path.clone())
+            .collect()
+    }
+
+    fn start_prompt(&mut self, prompt: Prompt, input: String) {
+        self.prompt = Some(prompt);
+        self.input.set_data(&input);
+        self.state = ComponentState::Editing;
+    }
+
+    fn close_prompt(&mut self) {
+        self.prompt = None;
+        self.input.clear();
+        self.state = ComponentState::Focused;
+    }
+
+    fn report<T>(&mut self, result: Result<T>, success: String) {
+        self.message = Some(match result {
+            Ok(_) => success,
+            Err(err) => err.to_string(),
+        });
+    }
+
+    fn open(&mut self, event_sender: &EventSender) {
+        let path = match self.selected_item() {
+            Some(item) if !item.is_dir => item.path.clone(),
+            _ => return,
+        };
+        match self.store.load(&path) {
+            Ok(saved) => {
+                self.message = Some(format!("Opened {}", path.display()));
+                event_sender.send(Event::LoadRequest(
+                    saved.request.clone(),
+                    RequestOrigin::Collection(path, Box::new(saved)),
+                ));
+            }
+            Err(err) => self.message = Some(err.to_string()),
+        }
+    }
+
+    pub fn save(
+        &mut self,
+        path: Option<PathBuf>,
+        origin: &mut RequestOrigin,
+        request: RequestData,
+    ) {
+        let (path, mut saved) = match (path, &*origin) {
+            (Some(path), RequestOrigin::Collection(opened_path, saved)) if path == *opened_path => {
+                (path, (**saved).clone())
+            }
+            (Some(path), _) => (path, SavedRequest::default()),
+            (None, RequestOrigin::Collection(opened_path, saved)) => {
+                (opened_path.clone(), (**saved).clone())
+            }
+            (None, _) => {
+                self.start_prompt(Prompt::SaveAs, String::new());
+                return;
+            }
+        };
+        saved.request = request;
+
+        let result = self.store.save(&path, &saved);
+        let succeeded = result.is_ok();
+        self.report(result, format!("Saved {}", path.display()));
+        if succeeded {
+            *origin = RequestOrigin::Collection(path.clone(), Box::new(saved));
+            self.refresh();
+            self.select(&path);
+        }
+    }
+
+    fn submit_prompt(&mut self, prompt: Prompt, event_sender: &EventSender) {
+        let input = PathBuf::from(self.input.get_data().trim());
+        let selected = self
+            .selected_item()
+            .map(|item| (item.path.clone(), item.is_dir));
+        self.close_prompt();
+
+        match (prompt, selected) {
+            (Prompt::SaveAs, _) if self.store.exists(&input) => {
+                self.start_prompt(Prompt::Overwrite, input.display().to_string());
+            }
+            (Prompt::SaveAs, _) | (Prompt::Overwrite, _) => {
+                event_sender.send(Event::SaveRequest(Some(input)))
+            }
+            (Prompt::Rename, Some((from, false))) => {
+                let result = self.store.rename(&from, &input);
+                if result.is_ok() {
+                    event_sender.send(Event::SavedRequestRenamed(from, input.clone()));
+                }
+                self.report(result, format!("Renamed to {}", input.display()));
+                self.refresh();
+                self.select(&input);
+            }
+            (Prompt::Rename, Some((from, true))) => {
+                let requests = self.requests_in(&from);
+                let result = self.store.rename_folder(&from, &input);
+                if result.is_ok() {
+                    for path in requests {
+                        let to = input.join(path.strip_prefix(&from).unwrap_or(&path));
+                        event_sender.send(Event::SavedRequestRenamed(path, to));
+                    }
+                }
+                self.report(result, format!("Renamed to {}", input.display()));
+                self.refresh();
+                self.select(&input);
+            }
+            (Prompt::Delete, Some((path, false))) => {
+                let result = self.store.delete(&path);
+                if result.is_ok() {
+                    event_sender.send(Event::SavedRequestDeleted(path.clone()));
+                }
+                self.report(result, format!("Deleted {}", path.display()));
+                self.refresh();
+            }
+            (Prompt::DeleteFolder, Some((path, true))) => {
+                let requests = self.requests_in(&path);
+                let result = self.store.delete_folder(&path);
+                if result.is_ok() {
+                    for request in requests {
+                        event_sender.send(Event::SavedRequestDeleted(request));
+                    }
+                }
+                self.report(result, format!("Deleted {}", path.display()));
+                self.refresh();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Component for Collections {
+    fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        match self.state {
+            ComponentState::Focused => match k {
+                _ if keymap::get().is(Action::Up, k) => {
+                    if self.selected > 0 {
+                        self.selected -= 1;
+                    }
+                }
+                _ if keymap::get().is(Action::Down, k) => {
+                    if self.selected + 1 < self.items.len() {
+                        self.selected += 1;
+                    }
+                }
+                Key::Char('\n') => self.open(&event_sender),
+                _ if keymap::get().is(Action::SaveRequest, k) => {
+                    event_sender.send(Event::SaveRequest(None))
+                }
+                _ if keymap::get().is(Action::SaveRequestAs, k) => {
+                    let folder = match self.selected_item() {
+                        Some(item) if item.is_dir => format!("{}/", item.path.display()),
+                        Some(item) => item
+                            .path
+                            .parent()
+                            .filter(|p| !p.as_os_str().is_empty())
+                            .map(|p| format!("{}/", p.display()))
+                            .unwrap_or_default(),
+                        None => String::new(),
+                    };
+                    self.start_prompt(Prompt::SaveAs, folder);
+                }
+                _ if keymap::get().is(Action::Rename, k) => {
+                    if let Some(item) = self.selected_item() {
+                        let path = item.path.display().to_string();
+                        self.start_prompt(Prompt::Rename, path);
+                    }
+                }
+                _ if keymap::get().is(Action::DuplicateRequest, k) => {
+                    if let Some(path) = self
+                        .selected_item()
+                        .filter(|item| !item.is_dir)
+                        .map(|item| item.path.clone())
+                    {
+                        let result = self.store.duplicate(&path);
+                        if let Ok(copy_path) = &result {
+                            let copy_path = copy_path.clone();
+                            self.refresh();
+                            self.select(&copy_path);
+                        }
+                        self.report(result, format!("Duplicated {}", path.display()));
+                    }
+                }
+                _ if keymap::get().is(Action::Delete, k) => {
+                    match self.selected_item().map(|item| item.is_dir) {
+                        Some(false) => self.start_prompt(Prompt::Delete, String::new()),
+                        Some(true) => self.start_prompt(Prompt::DeleteFolder, String::new()),
+                        None => {}
+                    }
+                }
+                Key::Esc => {
+                    self.visible = false;
+                    event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
+                }
+                _ => default_key_handle(k, event_sender)?,
+            },
+            ComponentState::Editing => match self.prompt {
+                Some(prompt @ (Prompt::Delete | Prompt::DeleteFolder | Prompt::Overwrite)) => {
+                    if keymap::get().is(Action::Confirm, k) {
+                        self.submit_prompt(prompt, &event_sender);
+                    } else {
+                        self.close_prompt();
+                    }
+                }
+                Some(prompt) => match k {
+                    Key::Esc => self.close_prompt(),
+                    Key::Char('\n') => self.submit_prompt(prompt, &event_sender),
+                    _ => {
+                        self.input.key_handle(k);
+                    }
+                },
+                None => self.state = ComponentState::Focused,
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let keymap = keymap::get();
+        let (list_area, footer_area) = {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(4)].as_ref())
+                .split(area);
+            (chunks[0], chunks[1])
+        };
+
+        let opened_path = self.opened.as_ref();
+        let items = self
+            .items
+            .iter()
+            .map(|item| {
+                let marker = if item.is_dir {
+                    "▾ "
+                } else if Some(&item.path) == opened_path {
+                    "* "
+                } else {
+                    "  "
+                };
+                let style = if item.is_dir {
+                    Style::default().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Spans::from(vec![
+                    Span::raw("  ".repeat(item.depth)),
+                    Span::raw(marker),
+                    Span::styled(item.name.clone(), style),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+        let list = List::new(items)
+            .style(match self.state {
+                ComponentState::Focused => Style::default().fg(Color::Green),
+                _ => Style::default(),
+            })
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(keymap.title(Action::Collections, "COLLECTIONS")),
+            );
+        let mut list_state = ListState::default();
+        if !self.items.is_empty() {
+            list_state.select(Some(self.selected));
+        }
+
+        let footer = match self.prompt {
+            Some(Prompt::Overwrite) => Paragraph::new(format!(
+                "{} already exists. {}",
+                self.input.get_data(),
+                Prompt::Overwrite.label()
+            ))
+            .style(Style::default().fg(Color::LightGreen)),
+            Some(prompt) => Paragraph::new(Spans::from(vec![
+                Span::raw(prompt.label()),
+                Span::raw(self.input.get_data()),
+            ]))
+            .style(Style::default().fg(Color::LightGreen)),
+            None => Paragraph::new(vec![
+                Spans::from(Span::styled(
+                    format!(
+                        "Enter:open {}:save {}:save as {}:rename {}:duplicate {}:delete",
+                        keymap.label(Action::SaveRequest),
+                        keymap.label(Action::SaveRequestAs),
+                        keymap.label(Action::Rename),
+                        keymap.label(Action::DuplicateRequest),
+                        keymap.label(Action::Delete)
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                )),
+                Spans::from(Span::raw(self.message.clone().unwrap_or_default())),
+            ]),
+        }
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL));
+
+        f.render_stateful_widget(list, list_area, &mut list_state);
+        f.render_widget(footer, footer_area);
+
+        if let Some(prompt) = self.prompt.filter(|prompt| *prompt != Prompt::Overwrite) {
+            f.set_cursor(
+                footer_area.x + 1 + prompt.label().len() as u16 + self.input.x_cursor_postion(),
+                footer_area.y + 1,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+        if state.is_focused() {
+            self.visible = true;
+            self.refresh();
+        } else {
+            self.prompt = None;
+        }
+    }
+}
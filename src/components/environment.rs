@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::environment::EnvironmentStore;
+use crate::keymap::{self, Action};
+
+use anyhow::Result;
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::Paragraph,
+    Frame,
+};
+
+#[derive(Default)]
+pub struct Environment {
+    store: Option<EnvironmentStore>,
+    error: Option<String>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        let mut environment = Self::default();
+        environment.reload();
+        environment
+    }
+
+    pub fn reload(&mut self) {
+        match EnvironmentStore::load() {
+            Ok(store) => {
+                self.store = Some(store);
+                self.error = None;
+            }
+            Err(err) => self.error = Some(err.to_string()),
+        }
+    }
+
+    pub fn switch_next(&mut self) {
+        self.reload();
+        if let Some(store) = &mut self.store {
+            let names = store.names().into_iter().cloned().collect::<Vec<String>>();
+            let next = match store.active() {
+                None => names.first(),
+                Some(active) => names
+                    .iter()
+                    .position(|name| name == active)
+                    .and_then(|i| names.get(i + 1)),
+            };
+            if let Err(err) = store.set_active(next.map(|name| name.as_str())) {
+                self.error = Some(err.to_string());
+            }
+        }
+    }
+
+    pub fn variables(&self) -> HashMap<String, String> {
+        self.store
+            .as_ref()
+            .map(|store| store.variables())
+            .unwrap_or_default()
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let (name, style) = match (&self.error, self.store.as_ref().and_then(|s| s.active())) {
+            (Some(err), _) => (format!("error: {}", err), Style::default().fg(Color::Red)),
+            (None, Some(active)) => (
+                active.to_string(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            (None, None) => ("none".to_string(), Style::default().fg(Color::DarkGray)),
+        };
+        let widget = Paragraph::new(Spans::from(vec![
+            Span::styled(
+                format!(
+                    "{}: ",
+                    keymap::get().title(Action::SwitchEnvironment, "ENV")
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(name, style),
+        ]))
+        .alignment(Alignment::Right);
+
+        f.render_widget(widget, area);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use crate::components::{Component, ComponentPosition, ComponentState};
+use crate::event::{Event, EventSender};
+use crate::keymap::{self, Action};
+use crate::request_data::RequestData;
+use crate::snippet::{self, FORMATS};
+use crate::ui::default_key_handle;
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs},
+    Frame,
+};
+
+pub struct Export {
+    data: RequestData,
+    selected: usize,
+    scroll: u16,
+    state: ComponentState,
+}
+
+impl Default for Export {
+    fn default() -> Self {
+        Self {
+            data: RequestData::default(),
+            selected: 0,
+            scroll: 0,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl Export {
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    pub fn set_data(&mut self, data: RequestData) {
+        self.data = data;
+        self.scroll = 0;
+    }
+
+    pub fn get_text(&self) -> Result<String, String> {
+        snippet::render(FORMATS[self.selected], &self.data).map_err(|err| err.to_string())
+    }
+}
+
+impl Component for Export {
+    fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        match k {
+            _ if keymap::get().is(Action::NextFormat, k) => {
+                self.selected = (self.selected + 1) % FORMATS.len();
+                self.scroll = 0;
+            }
+            _ if keymap::get().is(Action::PreviousFormat, k) => {
+                self.selected = (self.selected + FORMATS.len() - 1) % FORMATS.len();
+                self.scroll = 0;
+            }
+            _ if keymap::get().is(Action::Down, k) => self.scroll += 1,
+            _ if keymap::get().is(Action::Up, k) => self.scroll = self.scroll.saturating_sub(1),
+            _ if keymap::get().is(Action::Copy, k) => {
+                if let Ok(text) = self.get_text() {
+                    event_sender.send(Event::Copy(text));
+                }
+            }
+            Key::Esc => {
+                event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
+            }
+            _ => default_key_handle(k, event_sender)?,
+        }
+
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let keymap = keymap::get();
+        let (tabs_area, text_area, help_area) = {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Length(3),
+                        Constraint::Min(1),
+                        Constraint::Length(1),
+                    ]
+                    .as_ref(),
+                )
+                .split(area);
+            (chunks[0], chunks[1], chunks[2])
+        };
+
+        let tabs = Tabs::new(
+            FORMATS
+                .iter()
+                .map(|format| Spans::from(format.as_str()))
+                .collect(),
+        )
+        .select(self.selected)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::REVERSED),
+        )
+        .block(Block::default().borders(Borders::ALL).title("EXPORT"));
+
+        let text = match self.get_text() {
+            Ok(text) => Paragraph::new(text),
+            Err(err) => Paragraph::new(err).style(Style::default().fg(Color::Red)),
+        }
+        .scroll((self.scroll, 0))
+        .block(Block::default().borders(Borders::ALL));
+
+        let help = Paragraph::new(Spans::from(vec![
+            Span::styled(
+                format!(
+                    "{}/{}",
+                    keymap.short_label(Action::PreviousFormat),
+                    keymap.short_label(Action::NextFormat)
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": format  "),
+            Span::styled(
+                format!(
+                    "{}/{}",
+                    keymap.short_label(Action::Down),
+                    keymap.short_label(Action::Up)
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": scroll  "),
+            Span::styled(
+                keymap.label(Action::Copy),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": copy  "),
+            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(": close"),
+        ]));
+
+        f.render_widget(Clear, area);
+        f.render_widget(tabs, tabs_area);
+        f.render_widget(text, text_area);
+        f.render_widget(help, help_area);
+
+        Ok(())
+    }
+
+    fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+}
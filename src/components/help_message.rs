@@ -1,5 +1,6 @@
 use crate::components::{Component, ComponentState};
 use crate::event::EventSender;
+use crate::keymap::{self, Action};
 
 use anyhow::Result;
 use termion::event::Key;
@@ -8,10 +9,23 @@ use tui::{
     layout::Rect,
     style::{Modifier, Style},
     text::{Span, Spans, Text},
-    widgets::Paragraph,
+    widgets::{Paragraph, Wrap},
     Frame,
 };
 
+const SHOWN_ACTIONS: [Action; 10] = [
+    Action::SendRequest,
+    Action::History,
+    Action::Collections,
+    Action::Variables,
+    Action::Import,
+    Action::HttpFile,
+    Action::Export,
+    Action::NewTab,
+    Action::NextTab,
+    Action::Help,
+];
+
 pub struct HelpMessage {}
 
 impl Component for HelpMessage {
@@ -20,16 +34,25 @@ impl Component for HelpMessage {
     }
 
     fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let keymap = keymap::get();
         let (msg, style) = (
-            vec![
-                Span::styled("Ctrl + s", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(": send request"),
-            ],
+            SHOWN_ACTIONS
+                .iter()
+                .flat_map(|action| {
+                    vec![
+                        Span::styled(
+                            keymap.label(*action),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw(format!(": {}  ", action.description())),
+                    ]
+                })
+                .collect::<Vec<Span>>(),
             Style::default(),
         );
         let mut text = Text::from(Spans::from(msg));
         text.patch_style(style);
-        let help_message = Paragraph::new(text);
+        let help_message = Paragraph::new(text).wrap(Wrap { trim: true });
         f.render_widget(help_message, area);
         Ok(())
     }
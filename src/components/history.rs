@@ -0,0 +1,269 @@
+use crate::components::{line_input::LineInput, Component, ComponentPosition, ComponentState};
+use crate::event::{Event, EventSender, RequestOrigin};
+use crate::har;
+use crate::history::{HistoryEntry, HistoryStore};
+use crate::keymap::{self, Action};
+use crate::ui::default_key_handle;
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+const HAR_EXPORT_FILE: &str = "history.har";
+const REDACTED_MESSAGE: &str = "Credentials are not kept in history, fill them in before sending";
+
+pub struct History {
+    store: HistoryStore,
+    search: LineInput,
+    selected: usize,
+    confirm_export: bool,
+    state: ComponentState,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            store: HistoryStore::load(),
+            search: LineInput::default(),
+            selected: 0,
+            confirm_export: false,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl History {
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    pub fn append(&mut self, entry: HistoryEntry) -> Result<()> {
+        self.store.append(entry)
+    }
+
+    fn filtered(&self) -> Vec<&HistoryEntry> {
+        let search = self.search.get_data().to_lowercase();
+        self.store
+            .entries()
+            .iter()
+            .rev()
+            .filter(|entry| {
+                search.is_empty()
+                    || format!("{} {}", entry.request.method, entry.request.url)
+                        .to_lowercase()
+                        .contains(&search)
+            })
+            .collect()
+    }
+
+    fn selected_entry(&self) -> Option<&HistoryEntry> {
+        self.filtered().get(self.selected).copied()
+    }
+
+    fn export(&self, event_sender: &EventSender) {
+        // Oldest first, as browsers write HAR files.
+        let mut entries = self.filtered();
+        entries.reverse();
+        let path = PathBuf::from(HAR_EXPORT_FILE);
+        event_sender.send(Event::Notify(match har::export(&path, &entries) {
+            Ok(()) => Ok(format!(
+                "Exported {} entries to {}",
+                entries.len(),
+                path.display()
+            )),
+            Err(err) => Err(format!("Export failed: {}", err)),
+        }));
+    }
+}
+
+impl Component for History {
+    fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        if self.confirm_export {
+            self.confirm_export = false;
+            if keymap::get().is(Action::Confirm, k) {
+                self.export(&event_sender);
+            }
+            return Ok(());
+        }
+
+        match self.state {
+            ComponentState::Focused => match k {
+                _ if keymap::get().is(Action::Up, k) => {
+                    if self.selected > 0 {
+                        self.selected -= 1;
+                    }
+                }
+                _ if keymap::get().is(Action::Down, k) => {
+                    if self.selected + 1 < self.filtered().len() {
+                        self.selected += 1;
+                    }
+                }
+                Key::Char('/') => {
+                    self.state = ComponentState::Editing;
+                }
+                Key::Char('\n') => {
+                    if let Some(entry) = self.selected_entry() {
+                        event_sender.send(Event::LoadRequest(
+                            entry.request.clone(),
+                            RequestOrigin::Other,
+                        ));
+                        if entry.has_redacted_secrets() {
+                            event_sender.send(Event::Notify(Err(REDACTED_MESSAGE.to_string())));
+                        }
+                    }
+                }
+                _ if keymap::get().is(Action::Resend, k) => match self.selected_entry() {
+                    Some(entry) if entry.has_redacted_secrets() => {
+                        event_sender.send(Event::Notify(Err(REDACTED_MESSAGE.to_string())));
+                    }
+                    Some(entry) => {
+                        event_sender.send(Event::ResendRequest(entry.request.clone()));
+                    }
+                    None => {}
+                },
+                _ if keymap::get().is(Action::ExportHar, k) => {
+                    if Path::new(HAR_EXPORT_FILE).exists() {
+                        self.confirm_export = true;
+                    } else {
+                        self.export(&event_sender);
+                    }
+                }
+                Key::Esc => {
+                    event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
+                }
+                _ => default_key_handle(k, event_sender)?,
+            },
+            ComponentState::Editing => match k {
+                Key::Char('\n') | Key::Esc => {
+                    self.state = ComponentState::Focused;
+                }
+                _ => {
+                    let search = self.search.get_data();
+                    if !self.search.key_handle(k) {
+                        default_key_handle(k, event_sender)?;
+                    } else if self.search.get_data() != search {
+                        self.selected = 0;
+                    }
+                }
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let keymap = keymap::get();
+        let (search_area, list_area, help_area) = {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Length(3),
+                        Constraint::Min(1),
+                        Constraint::Length(1),
+                    ]
+                    .as_ref(),
+                )
+                .split(area);
+            (chunks[0], chunks[1], chunks[2])
+        };
+
+        let search = Paragraph::new(self.search.get_data())
+            .style(match self.state {
+                ComponentState::Editing => Style::default().fg(Color::LightGreen),
+                _ => Style::default(),
+            })
+            .block(Block::default().borders(Borders::ALL).title("[/]SEARCH"));
+
+        let items = self
+            .filtered()
+            .iter()
+            .map(|entry| {
+                let (status, status_style) = match (entry.status, &entry.error) {
+                    (Some(status), _) if status < 400 => {
+                        (status.to_string(), Style::default().fg(Color::Green))
+                    }
+                    (Some(status), _) => (status.to_string(), Style::default().fg(Color::Red)),
+                    (None, _) => ("ERR".to_string(), Style::default().fg(Color::Magenta)),
+                };
+                let delay = entry
+                    .delay_ms
+                    .map(|ms| format!("{:>6}ms", ms))
+                    .unwrap_or_else(|| format!("{:>8}", "-"));
+                ListItem::new(Spans::from(vec![
+                    Span::styled(
+                        entry.sent_at.format("%Y-%m-%d %H:%M:%S ").to_string(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::raw(format!("{:<8}", entry.request.method)),
+                    Span::styled(format!("{:<4}", status), status_style),
+                    Span::raw(format!("{} ", delay)),
+                    Span::raw(entry.request.url.clone()),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+        let list = List::new(items)
+            .style(match self.state {
+                ComponentState::Focused => Style::default().fg(Color::Green),
+                _ => Style::default(),
+            })
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(Block::default().borders(Borders::ALL).title("HISTORY"));
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.selected));
+
+        let help = if self.confirm_export {
+            Paragraph::new(format!(
+                "Overwrite {}? ({}/n)",
+                HAR_EXPORT_FILE,
+                keymap.label(Action::Confirm)
+            ))
+            .style(Style::default().fg(Color::LightGreen))
+        } else {
+            Paragraph::new(Spans::from(vec![
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": restore  "),
+                Span::styled(
+                    keymap.label(Action::Resend),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(": re-send  "),
+                Span::styled(
+                    keymap.label(Action::ExportHar),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(": export HAR  "),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": close"),
+            ]))
+        };
+
+        f.render_widget(Clear, area);
+        f.render_widget(search, search_area);
+        f.render_stateful_widget(list, list_area, &mut list_state);
+        f.render_widget(help, help_area);
+
+        if let ComponentState::Editing = self.state {
+            f.set_cursor(
+                search_area.x + 1 + self.search.x_cursor_postion(),
+                search_area.y + 1,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::components::{line_input::LineInput, Component, ComponentPosition, ComponentState};
+use crate::event::{Event, EventSender, RequestOrigin};
+use crate::http_file::HttpFile;
+use crate::keymap::{self, Action};
+use crate::request_data::RequestData;
+use crate::ui::default_key_handle;
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+const OPEN_LABEL: &str = "Open: ";
+
+pub struct HttpFileView {
+    file: Option<HttpFile>,
+    selected: usize,
+    // The request of this file open in the current tab, marked in the list.
+    opened: Option<(PathBuf, usize)>,
+    input: LineInput,
+    message: Option<String>,
+    state: ComponentState,
+}
+
+impl Default for HttpFileView {
+    fn default() -> Self {
+        Self {
+            file: None,
+            selected: 0,
+            opened: None,
+            input: LineInput::default(),
+            message: None,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl HttpFileView {
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    // File variables only apply to requests loaded from that file.
+    pub fn variables(
+        &self,
+        origin: &RequestOrigin,
+        base: HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        match (&self.file, origin) {
+            (Some(file), RequestOrigin::HttpFile(path, _)) if *path == file.path => {
+                file.variables(&base)
+            }
+            _ => base,
+        }
+    }
+
+    pub fn open(&mut self, path: &Path) {
+        match HttpFile::load(path) {
+            Ok(file) => {
+                self.message = Some(format!(
+                    "Opened {} ({} requests)",
+                    path.display(),
+                    file.requests.len()
+                ));
+                self.file = Some(file);
+                self.selected = 0;
+            }
+            Err(err) => self.message = Some(err.to_string()),
+        }
+    }
+
+    pub fn set_opened(&mut self, opened: Option<(PathBuf, usize)>) {
+        self.opened = opened;
+    }
+
+    pub fn write(&mut self, origin: &RequestOrigin, data: RequestData) {
+        let (file, index) = match (&mut self.file, origin) {
+            (Some(file), RequestOrigin::HttpFile(path, index)) if *path == file.path => {
+                (file, *index)
+            }
+            _ => {
+                self.message = Some("Open a request from the file first".to_string());
+                return;
+            }
+        };
+        self.message = Some(match file.update(index, &data) {
+            Ok(()) => format!("Wrote request to {}", file.path.display()),
+            Err(err) => err.to_string(),
+        });
+    }
+
+    fn start_prompt(&mut self) {
+        let path = self
+            .file
+            .as_ref()
+            .map(|file| file.path.display().to_string())
+            .unwrap_or_default();
+        self.input.set_data(&path);
+        self.state = ComponentState::Editing;
+    }
+
+    fn load(&mut self, event_sender: &EventSender) {
+        let request = self.file.as_ref().and_then(|file| {
+            file.requests
+                .get(self.selected)
+                .map(|request| (file, request))
+        });
+        if let Some((file, request)) = request {
+            event_sender.send(Event::LoadRequest(
+                request.request.clone(),
+                RequestOrigin::HttpFile(file.path.clone(), self.selected),
+            ));
+            self.message = Some(format!("Loaded {}", request.name));
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.file.as_ref().map_or(0, |file| file.requests.len())
+    }
+}
+
+impl Component for HttpFileView {
+    fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        if let ComponentState::Editing = self.state {
+            match k {
+                Key::Esc => self.state = ComponentState::Focused,
+                Key::Char('\n') => {
+                    let path = PathBuf::from(self.input.get_data().trim());
+                    self.state = ComponentState::Focused;
+                    self.open(&path);
+                }
+                _ => {
+                    self.input.key_handle(k);
+                }
+            }
+            return Ok(());
+        }
+
+        match k {
+            _ if keymap::get().is(Action::Up, k) => self.selected = self.selected.saturating_sub(1),
+            _ if keymap::get().is(Action::Down, k) => {
+                if self.selected + 1 < self.len() {
+                    self.selected += 1;
+                }
+            }
+            Key::Char('\n') => self.load(&event_sender),
+            _ if keymap::get().is(Action::WriteHttpFile, k) => {
+                event_sender.send(Event::WriteHttpFile)
+            }
+            _ if keymap::get().is(Action::OpenHttpFile, k) => self.start_prompt(),
+            _ if keymap::get().is(Action::ReloadHttpFile, k) => {
+                if let Some(path) = self.file.as_ref().map(|file| file.path.clone()) {
+                    self.open(&path);
+                }
+            }
+            Key::Esc => {
+                event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
+            }
+            _ => default_key_handle(k, event_sender)?,
+        }
+
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let keymap = keymap::get();
+        let (list_area, footer_area) = {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(4)].as_ref())
+                .split(area);
+            (chunks[0], chunks[1])
+        };
+
+        let title = match &self.file {
+            Some(file) => format!("HTTP FILE {}", file.path.display()),
+            None => "HTTP FILE".to_string(),
+        };
+        let opened = match (&self.file, &self.opened) {
+            (Some(file), Some((path, index))) if *path == file.path => Some(*index),
+            _ => None,
+        };
+        let items = self
+            .file
+            .iter()
+            .flat_map(|file| file.requests.iter().enumerate())
+            .map(|(i, request)| {
+                let marker = if Some(i) == opened { "* " } else { "  " };
+                ListItem::new(Spans::from(vec![
+                    Span::raw(marker),
+                    Span::styled(
+                        format!("{:<7}", request.request.method),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(request.name.clone()),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+        let list = List::new(items)
+            .style(Style::default().fg(Color::Green))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(Block::default().borders(Borders::ALL).title(title));
+        let mut list_state = ListState::default();
+        if self.len() > 0 {
+            list_state.select(Some(self.selected));
+        }
+
+        let footer = match self.state {
+            ComponentState::Editing => Paragraph::new(Spans::from(vec![
+                Span::raw(OPEN_LABEL),
+                Span::raw(self.input.get_data()),
+            ]))
+            .style(Style::default().fg(Color::LightGreen)),
+            _ => Paragraph::new(vec![
+                Spans::from(Span::styled(
+                    format!(
+                        "Enter:load {}:write back {}:open file {}:reload Esc:close",
+                        keymap.label(Action::WriteHttpFile),
+                        keymap.label(Action::OpenHttpFile),
+                        keymap.label(Action::ReloadHttpFile)
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                )),
+                Spans::from(Span::raw(self.message.clone().unwrap_or_default())),
+            ]),
+        }
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL));
+
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(list, list_area, &mut list_state);
+        f.render_widget(footer, footer_area);
+
+        if let ComponentState::Editing = self.state {
+            f.set_cursor(
+                footer_area.x + 1 + OPEN_LABEL.len() as u16 + self.input.x_cursor_postion(),
+                footer_area.y + 1,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+        // Without a file there is nothing to pick from, so ask for one straight away.
+        if state.is_focused() && self.file.is_none() {
+            self.start_prompt();
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use crate::components::{line_input::LineInput, Component, ComponentPosition, ComponentState};
+use crate::curl;
+use crate::event::{Event, EventSender, RequestOrigin};
+use crate::request_data::RequestData;
+use crate::ui::default_key_handle;
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub struct Import {
+    input: LineInput,
+    parsed: Option<curl::Import>,
+    error: Option<String>,
+    state: ComponentState,
+}
+
+impl Default for Import {
+    fn default() -> Self {
+        Self {
+            input: LineInput::default(),
+            parsed: None,
+            error: None,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl Import {
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    fn load(&mut self, request: RequestData, event_sender: &EventSender) {
+        self.input.clear();
+        self.parsed = None;
+        self.error = None;
+        event_sender.send(Event::LoadRequest(request, RequestOrigin::Other));
+    }
+
+    fn submit(&mut self, event_sender: &EventSender) {
+        match curl::parse(&self.input.get_data()) {
+            Ok(import) if import.warnings.is_empty() => self.load(import.request, event_sender),
+            Ok(import) => {
+                self.error = None;
+                self.parsed = Some(import);
+            }
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+impl Component for Import {
+    fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        if let Some(parsed) = self.parsed.take() {
+            match k {
+                Key::Char('\n') => self.load(parsed.request, &event_sender),
+                Key::Esc => {}
+                _ => self.parsed = Some(parsed),
+            }
+            return Ok(());
+        }
+
+        match k {
+            Key::Esc => {
+                event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
+            }
+            Key::Char('\n') => {
+                let input = self.input.get_data();
+                // A trailing backslash means the pasted command continues on the next line.
+                if input.ends_with('\\') {
+                    self.input.key_handle(Key::Backspace);
+                    self.input.key_handle(Key::Char(' '));
+                } else {
+                    self.submit(&event_sender);
+                }
+            }
+            _ => {
+                if !self.input.key_handle(k) {
+                    default_key_handle(k, event_sender)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let (input_area, message_area, help_area) = {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Length(3),
+                        Constraint::Min(1),
+                        Constraint::Length(1),
+                    ]
+                    .as_ref(),
+                )
+                .split(area);
+            (chunks[0], chunks[1], chunks[2])
+        };
+
+        let cursor = self.input.x_cursor_postion();
+        let scroll = cursor.saturating_sub(input_area.width.saturating_sub(3));
+        let input = Paragraph::new(self.input.get_data())
+            .style(match self.parsed {
+                None => Style::default().fg(Color::LightGreen),
+                Some(_) => Style::default(),
+            })
+            .scroll((0, scroll))
+            .block(Block::default().borders(Borders::ALL).title("IMPORT CURL"));
+
+        let mut messages = vec![];
+        if let Some(err) = &self.error {
+            messages.push(Spans::from(Span::styled(
+                err.clone(),
+                Style::default().fg(Color::Red),
+            )));
+        }
+        if let Some(parsed) = &self.parsed {
+            messages.push(Spans::from(format!(
+                "{} {}",
+                parsed.request.method, parsed.request.url
+            )));
+            for warning in &parsed.warnings {
+                messages.push(Spans::from(Span::styled(
+                    warning.clone(),
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
+        }
+        let message = Paragraph::new(messages)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL));
+
+        let help = Paragraph::new(Spans::from(match self.parsed {
+            None => vec![
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": import  "),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": close"),
+            ],
+            Some(_) => vec![
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": import anyway  "),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": back"),
+            ],
+        }));
+
+        f.render_widget(Clear, area);
+        f.render_widget(input, input_area);
+        f.render_widget(message, message_area);
+        f.render_widget(help, help_area);
+
+        if self.parsed.is_none() {
+            f.set_cursor(input_area.x + 1 + cursor - scroll, input_area.y + 1);
+        }
+
+        Ok(())
+    }
+
+    fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+}
@@ -0,0 +1,108 @@
+use crate::components::{Component, ComponentPosition, ComponentState};
+use crate::event::{Event, EventSender};
+use crate::keymap::{self, Action, Context, Keymap, ACTIONS};
+use crate::ui::default_key_handle;
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Clear, Row, Table},
+    Frame,
+};
+
+pub struct KeyBindings {
+    scroll: usize,
+    state: ComponentState,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            scroll: 0,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl KeyBindings {
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+}
+
+fn context_label(action: Action) -> String {
+    let contexts = action.contexts();
+    if contexts.contains(&Context::Global) {
+        Context::Global.name().to_string()
+    } else {
+        contexts
+            .iter()
+            .map(|context| context.name())
+            .collect::<Vec<&str>>()
+            .join(", ")
+    }
+}
+
+impl Component for KeyBindings {
+    fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        let keymap = keymap::get();
+        match k {
+            _ if keymap.is(Action::Up, k) => self.scroll = self.scroll.saturating_sub(1),
+            _ if keymap.is(Action::Down, k) => {
+                if self.scroll + 1 < ACTIONS.len() {
+                    self.scroll += 1;
+                }
+            }
+            Key::Esc => {
+                event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
+            }
+            _ => default_key_handle(k, event_sender)?,
+        }
+
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let keymap = keymap::get();
+        let rows = ACTIONS.iter().skip(self.scroll).map(|action| {
+            Row::new(vec![
+                Cell::from(keymap.label(*action))
+                    .style(Style::default().add_modifier(Modifier::BOLD)),
+                Cell::from(action.description()),
+                Cell::from(context_label(*action)),
+                Cell::from(action.name()).style(Style::default().fg(Color::DarkGray)),
+            ])
+        });
+        let title = match Keymap::path() {
+            Some(path) => format!("KEY BINDINGS ({})", path.display()),
+            None => "KEY BINDINGS".to_string(),
+        };
+        let table = Table::new(rows)
+            .header(
+                Row::new(vec!["Keys", "Action", "Where", "Config name"])
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .widths(
+                [
+                    Constraint::Length(16),
+                    Constraint::Length(26),
+                    Constraint::Length(26),
+                    Constraint::Min(10),
+                ]
+                .as_ref(),
+            )
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_widget(Clear, area);
+        f.render_widget(table, area);
+
+        Ok(())
+    }
+
+    fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+}
@@ -0,0 +1,58 @@
+use termion::event::Key;
+use unicode_width::UnicodeWidthChar;
+
+#[derive(Default)]
+pub struct LineInput {
+    data: Vec<char>,
+    x_data_editing_at: usize,
+}
+
+impl LineInput {
+    pub fn key_handle(&mut self, k: Key) -> bool {
+        match k {
+            Key::Char(c) if !c.is_control() => {
+                self.data.insert(self.x_data_editing_at, c);
+                self.x_data_editing_at += 1;
+            }
+            Key::Backspace => {
+                if self.x_data_editing_at > 0 {
+                    self.data.remove(self.x_data_editing_at - 1);
+                    self.x_data_editing_at -= 1;
+                }
+            }
+            Key::Left => {
+                if self.x_data_editing_at > 0 {
+                    self.x_data_editing_at -= 1;
+                }
+            }
+            Key::Right => {
+                if self.x_data_editing_at < self.data.len() {
+                    self.x_data_editing_at += 1;
+                }
+            }
+            Key::Home | Key::Ctrl('a') => self.x_data_editing_at = 0,
+            Key::End | Key::Ctrl('e') => self.x_data_editing_at = self.data.len(),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn get_data(&self) -> String {
+        self.data.iter().collect()
+    }
+
+    pub fn set_data(&mut self, data: &str) {
+        self.data = data.chars().collect();
+        self.x_data_editing_at = self.data.len();
+    }
+
+    pub fn clear(&mut self) {
+        self.set_data("");
+    }
+
+    pub fn x_cursor_postion(&self) -> u16 {
+        self.data[..self.x_data_editing_at]
+            .iter()
+            .fold(0, |acc, c| acc + c.width().unwrap() as u16)
+    }
+}
@@ -1,6 +1,17 @@
+pub mod collections;
+pub mod environment;
+pub mod export;
 pub mod help_message;
+pub mod history;
+pub mod http_file;
+pub mod import;
+pub mod key_bindings;
+pub mod line_input;
 pub mod request;
 pub mod response;
+pub mod tab_bar;
+pub mod toast;
+pub mod variables;
 
 use crate::event::EventSender;
 
@@ -25,10 +36,7 @@ pub enum ComponentState {
 
 impl ComponentState {
     pub fn is_focused(&self) -> bool {
-        match *self {
-            ComponentState::UnFocused => false,
-            _ => true,
-        }
+        !matches!(*self, ComponentState::UnFocused)
     }
 }
 
@@ -37,8 +45,17 @@ pub enum ComponentPosition {
     RequestMethod,
     RequestUrl,
     RequestQuery,
+    RequestHeader,
+    RequestAuth,
     RequestBody,
     Response(response::ResponseComponents),
+    History,
+    Collections,
+    Variables,
+    Import,
+    Export,
+    HttpFile,
+    KeyBindings,
 }
 
 impl ComponentPosition {
@@ -47,26 +64,42 @@ impl ComponentPosition {
             ComponentPosition::RequestMethod => (0, 0),
             ComponentPosition::RequestUrl => (1, 0),
             ComponentPosition::RequestQuery => (0, 1),
-            ComponentPosition::RequestBody => (1, 1),
+            ComponentPosition::RequestAuth => (1, 1),
+            ComponentPosition::RequestHeader => (0, 2),
+            ComponentPosition::RequestBody => (1, 2),
             ComponentPosition::Response(response_component) => match response_component {
-                response::ResponseComponents::Body => (0, 2),
-                response::ResponseComponents::Header => (1, 2),
+                response::ResponseComponents::Body => (0, 3),
+                response::ResponseComponents::Header => (1, 3),
+                response::ResponseComponents::Tests => (2, 3),
             },
+            ComponentPosition::History => (-1, -1),
+            ComponentPosition::Collections => (-1, 0),
+            ComponentPosition::Variables => (-1, -2),
+            ComponentPosition::Import => (-1, -3),
+            ComponentPosition::Export => (-1, -4),
+            ComponentPosition::HttpFile => (-1, -5),
+            ComponentPosition::KeyBindings => (-1, -6),
         }
     }
 
     pub fn from_position(coordinate: (isize, isize)) -> Option<ComponentPosition> {
         match coordinate {
+            (-1, 0) => Some(ComponentPosition::Collections),
             (0, 0) => Some(ComponentPosition::RequestMethod),
             (1, 0) => Some(ComponentPosition::RequestUrl),
             (0, 1) => Some(ComponentPosition::RequestQuery),
-            (1, 1) => Some(ComponentPosition::RequestBody),
-            (0, 2) => Some(ComponentPosition::Response(
+            (1, 1) => Some(ComponentPosition::RequestAuth),
+            (0, 2) => Some(ComponentPosition::RequestHeader),
+            (1, 2) => Some(ComponentPosition::RequestBody),
+            (0, 3) => Some(ComponentPosition::Response(
                 response::ResponseComponents::Body,
             )),
-            (1, 2) => Some(ComponentPosition::Response(
+            (1, 3) => Some(ComponentPosition::Response(
                 response::ResponseComponents::Header,
             )),
+            (2, 3) => Some(ComponentPosition::Response(
+                response::ResponseComponents::Tests,
+            )),
             _ => None,
         }
     }
@@ -0,0 +1,331 @@
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    components::ComponentState,
+    event::EventSender,
+    keymap::{self, Action},
+    request_data::AuthData,
+    ui::default_key_handle,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AuthMode {
+    None,
+    Basic,
+    Bearer,
+    ApiKeyHeader,
+    ApiKeyQuery,
+}
+
+const AUTH_MODES: [AuthMode; 5] = [
+    AuthMode::None,
+    AuthMode::Basic,
+    AuthMode::Bearer,
+    AuthMode::ApiKeyHeader,
+    AuthMode::ApiKeyQuery,
+];
+
+impl AuthMode {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            AuthMode::None => "None",
+            AuthMode::Basic => "Basic",
+            AuthMode::Bearer => "Bearer Token",
+            AuthMode::ApiKeyHeader => "API Key (Header)",
+            AuthMode::ApiKeyQuery => "API Key (Query)",
+        }
+    }
+
+    fn fields(&self) -> &'static [AuthField] {
+        match *self {
+            AuthMode::None => &[],
+            AuthMode::Basic => &[AuthField::Username, AuthField::Password],
+            AuthMode::Bearer => &[AuthField::Token],
+            AuthMode::ApiKeyHeader | AuthMode::ApiKeyQuery => {
+                &[AuthField::KeyName, AuthField::KeyValue]
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum AuthField {
+    Username,
+    Password,
+    Token,
+    KeyName,
+    KeyValue,
+}
+
+impl AuthField {
+    fn label(&self) -> &'static str {
+        match *self {
+            AuthField::Username => "Username: ",
+            AuthField::Password => "Password: ",
+            AuthField::Token => "Token: ",
+            AuthField::KeyName => "Key: ",
+            AuthField::KeyValue => "Value: ",
+        }
+    }
+
+    fn is_secret(&self) -> bool {
+        matches!(
+            *self,
+            AuthField::Password | AuthField::Token | AuthField::KeyValue
+        )
+    }
+}
+
+pub struct Auth {
+    mode: AuthMode,
+    selected_mode_index: usize,
+    username: Vec<char>,
+    password: Vec<char>,
+    token: Vec<char>,
+    key_name: Vec<char>,
+    key_value: Vec<char>,
+    field_editing_at: usize,
+    x_data_editing_at: usize,
+    state: ComponentState,
+}
+
+impl Default for Auth {
+    fn default() -> Self {
+        Self {
+            mode: AUTH_MODES[0],
+            selected_mode_index: 0,
+            username: vec![],
+            password: vec![],
+            token: vec![],
+            key_name: vec![],
+            key_value: vec![],
+            field_editing_at: 0,
+            x_data_editing_at: 0,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl Auth {
+    pub fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        match self.state {
+            ComponentState::Focused => match k {
+                Key::Char('\n') => {
+                    if !self.mode.fields().is_empty() {
+                        self.field_editing_at = 0;
+                        self.x_data_editing_at = self.field_data(self.editing_field()).len();
+                        self.state = ComponentState::Editing;
+                    }
+                }
+                Key::Right => {
+                    self.selected_mode_index = (self.selected_mode_index + 1) % AUTH_MODES.len();
+                    self.mode = AUTH_MODES[self.selected_mode_index];
+                }
+                Key::Left => {
+                    self.selected_mode_index =
+                        (self.selected_mode_index + AUTH_MODES.len() - 1) % AUTH_MODES.len();
+                    self.mode = AUTH_MODES[self.selected_mode_index];
+                }
+                _ => default_key_handle(k, event_sender)?,
+            },
+            ComponentState::Editing => match k {
+                Key::Char('\t') | Key::Char('\n') | Key::Down => {
+                    self.field_editing_at = (self.field_editing_at + 1) % self.mode.fields().len();
+                    self.x_data_editing_at = self.field_data(self.editing_field()).len();
+                }
+                Key::Up => {
+                    let len = self.mode.fields().len();
+                    self.field_editing_at = (self.field_editing_at + len - 1) % len;
+                    self.x_data_editing_at = self.field_data(self.editing_field()).len();
+                }
+                Key::Char(c) if !c.is_ascii_control() => {
+                    let at = self.x_data_editing_at;
+                    self.field_data_mut(self.editing_field()).insert(at, c);
+                    self.x_data_editing_at += 1;
+                }
+                Key::Backspace => {
+                    if self.x_data_editing_at > 0 {
+                        let at = self.x_data_editing_at - 1;
+                        self.field_data_mut(self.editing_field()).remove(at);
+                        self.x_data_editing_at -= 1;
+                    }
+                }
+                Key::Left => {
+                    if self.x_data_editing_at > 0 {
+                        self.x_data_editing_at -= 1;
+                    }
+                }
+                Key::Right => {
+                    if self.x_data_editing_at < self.field_data(self.editing_field()).len() {
+                        self.x_data_editing_at += 1;
+                    }
+                }
+                Key::Esc => {
+                    self.state = ComponentState::Focused;
+                }
+                _ => default_key_handle(k, event_sender)?,
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn editing_field(&self) -> AuthField {
+        self.mode.fields()[self.field_editing_at]
+    }
+
+    fn field_data(&self, field: AuthField) -> &Vec<char> {
+        match field {
+            AuthField::Username => &self.username,
+            AuthField::Password => &self.password,
+            AuthField::Token => &self.token,
+            AuthField::KeyName => &self.key_name,
+            AuthField::KeyValue => &self.key_value,
+        }
+    }
+
+    fn field_data_mut(&mut self, field: AuthField) -> &mut Vec<char> {
+        match field {
+            AuthField::Username => &mut self.username,
+            AuthField::Password => &mut self.password,
+            AuthField::Token => &mut self.token,
+            AuthField::KeyName => &mut self.key_name,
+            AuthField::KeyValue => &mut self.key_value,
+        }
+    }
+
+    fn display_data(&self, field: AuthField) -> String {
+        if field.is_secret() {
+            "*".repeat(self.field_data(field).len())
+        } else {
+            self.field_data(field).iter().collect()
+        }
+    }
+
+    pub fn render<B: Backend>(
+        &mut self,
+        f: &mut Frame<B>,
+        area: Rect,
+        _parent_state: ComponentState,
+    ) -> Result<()> {
+        let mut lines = vec![Spans::from(vec![
+            Span::raw("Mode: "),
+            Span::raw(format!("< {} >", self.mode.as_str())),
+        ])];
+        lines.extend(self.mode.fields().iter().map(|field| {
+            Spans::from(vec![
+                Span::raw(field.label()),
+                Span::raw(self.display_data(*field)),
+            ])
+        }));
+
+        let widget = Paragraph::new(lines)
+            .style(match self.state {
+                ComponentState::Editing => Style::default().fg(Color::LightGreen),
+                ComponentState::Focused => Style::default().fg(Color::Green),
+                _ => Style::default(),
+            })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(keymap::get().title(Action::FocusAuth, "AUTH")),
+            );
+
+        f.render_widget(widget, area);
+
+        if let ComponentState::Editing = self.state {
+            f.set_cursor(
+                area.x + 1 + self.x_cursor_postion(),
+                area.y + 2 + self.field_editing_at as u16,
+            )
+        }
+        Ok(())
+    }
+
+    fn x_cursor_postion(&self) -> u16 {
+        let field = self.editing_field();
+        let data_width = if field.is_secret() {
+            self.x_data_editing_at as u16
+        } else {
+            self.field_data(field)[..self.x_data_editing_at]
+                .iter()
+                .fold(0, |acc, c| acc + c.width().unwrap() as u16)
+        };
+        field.label().len() as u16 + data_width
+    }
+
+    pub fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    pub fn get_data(&self) -> AuthData {
+        let data = |field| self.field_data(field).iter().collect::<String>();
+        match self.mode {
+            AuthMode::None => AuthData::None,
+            AuthMode::Basic => AuthData::Basic {
+                username: data(AuthField::Username),
+                password: data(AuthField::Password),
+            },
+            AuthMode::Bearer => AuthData::Bearer {
+                token: data(AuthField::Token),
+            },
+            AuthMode::ApiKeyHeader => AuthData::ApiKeyHeader {
+                key: data(AuthField::KeyName),
+                value: data(AuthField::KeyValue),
+            },
+            AuthMode::ApiKeyQuery => AuthData::ApiKeyQuery {
+                key: data(AuthField::KeyName),
+                value: data(AuthField::KeyValue),
+            },
+        }
+    }
+
+    pub fn set_data(&mut self, data: &AuthData) {
+        let chars = |s: &String| s.chars().collect::<Vec<char>>();
+        self.username.clear();
+        self.password.clear();
+        self.token.clear();
+        self.key_name.clear();
+        self.key_value.clear();
+        self.mode = match data {
+            AuthData::None => AuthMode::None,
+            AuthData::Basic { username, password } => {
+                self.username = chars(username);
+                self.password = chars(password);
+                AuthMode::Basic
+            }
+            AuthData::Bearer { token } => {
+                self.token = chars(token);
+                AuthMode::Bearer
+            }
+            AuthData::ApiKeyHeader { key, value } => {
+                self.key_name = chars(key);
+                self.key_value = chars(value);
+                AuthMode::ApiKeyHeader
+            }
+            AuthData::ApiKeyQuery { key, value } => {
+                self.key_name = chars(key);
+                self.key_value = chars(value);
+                AuthMode::ApiKeyQuery
+            }
+        };
+        self.selected_mode_index = AUTH_MODES.iter().position(|m| *m == self.mode).unwrap_or(0);
+        self.field_editing_at = 0;
+        self.x_data_editing_at = 0;
+    }
+}
@@ -10,7 +10,12 @@ use tui::{
 };
 use unicode_width::UnicodeWidthChar;
 
-use crate::{components::ComponentState, event::EventSender, ui::default_key_handle};
+use crate::{
+    components::ComponentState,
+    event::EventSender,
+    keymap::{self, Action},
+    ui::default_key_handle,
+};
 
 pub struct Body {
     data: Vec<Vec<char>>,
@@ -129,17 +134,16 @@ impl Body {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("[R]REQUEST BODY"),
+                .title(keymap::get().title(Action::FocusBody, "REQUEST BODY")),
         );
 
         f.render_widget(widget, area);
 
-        match self.state {
-            ComponentState::Editing => f.set_cursor(
+        if let ComponentState::Editing = self.state {
+            f.set_cursor(
                 area.x + 1 + self.x_cursor_postion(),
                 area.y + 1 + self.y_cursor_postion(),
-            ),
-            _ => {}
+            )
         }
         Ok(())
     }
@@ -163,6 +167,19 @@ impl Body {
     }
 
     pub fn get_data(&self) -> String {
-        self.data.iter().flatten().collect()
+        self.data
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn set_data(&mut self, data: &str) {
+        self.data = data
+            .split('\n')
+            .map(|line| line.chars().collect())
+            .collect();
+        self.x_data_editing_at = 0;
+        self.y_data_editing_at = 0;
     }
 }
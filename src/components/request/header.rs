@@ -0,0 +1,226 @@
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    components::ComponentState,
+    event::EventSender,
+    keymap::{self, Action},
+    request_data::HeaderData,
+    ui::default_key_handle,
+};
+
+const CHECKBOX_WIDTH: u16 = 4;
+
+pub struct Header {
+    data: Vec<Vec<char>>,
+    enabled: Vec<bool>,
+    x_data_editing_at: usize,
+    y_data_editing_at: usize,
+    state: ComponentState,
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self {
+            data: vec![vec![]],
+            enabled: vec![true],
+            x_data_editing_at: 0,
+            y_data_editing_at: 0,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl Header {
+    pub fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        match self.state {
+            ComponentState::Focused => match k {
+                Key::Char('\n') => {
+                    self.state = ComponentState::Editing;
+                }
+                Key::Char(' ') => {
+                    self.enabled[self.y_data_editing_at] = !self.enabled[self.y_data_editing_at];
+                }
+                Key::Up => self.move_up(),
+                Key::Down => self.move_down(),
+                _ => default_key_handle(k, event_sender)?,
+            },
+            ComponentState::Editing => match k {
+                Key::Char('\n') => {
+                    self.y_data_editing_at += 1;
+                    self.x_data_editing_at = 0;
+                    self.data.insert(self.y_data_editing_at, vec![]);
+                    self.enabled.insert(self.y_data_editing_at, true);
+                }
+                Key::Char(c) => {
+                    self.data[self.y_data_editing_at].insert(self.x_data_editing_at, c);
+                    self.x_data_editing_at += 1;
+                }
+                Key::Backspace => {
+                    if self.x_data_editing_at > 0 {
+                        self.data[self.y_data_editing_at].remove(self.x_data_editing_at - 1);
+                        self.x_data_editing_at -= 1;
+                    } else if self.y_data_editing_at > 0 {
+                        let removed = self.data.remove(self.y_data_editing_at);
+                        self.enabled.remove(self.y_data_editing_at);
+                        self.x_data_editing_at = self.data[self.y_data_editing_at - 1].len();
+                        self.data[self.y_data_editing_at - 1].extend(removed);
+                        self.y_data_editing_at -= 1;
+                    }
+                }
+                Key::Up => self.move_up(),
+                Key::Down => self.move_down(),
+                Key::Left => {
+                    if self.x_data_editing_at > 0 {
+                        self.x_data_editing_at -= 1;
+                    }
+                }
+                Key::Right => {
+                    if self.x_data_editing_at < self.data[self.y_data_editing_at].len() {
+                        self.x_data_editing_at += 1;
+                    }
+                }
+                Key::Esc => {
+                    self.state = ComponentState::Focused;
+                }
+                _ => default_key_handle(k, event_sender)?,
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn move_up(&mut self) {
+        if self.y_data_editing_at > 0 {
+            self.y_data_editing_at -= 1;
+            self.x_data_editing_at = self
+                .x_data_editing_at
+                .min(self.data[self.y_data_editing_at].len());
+        }
+    }
+
+    fn move_down(&mut self) {
+        if self.y_data_editing_at < self.data.len() - 1 {
+            self.y_data_editing_at += 1;
+            self.x_data_editing_at = self
+                .x_data_editing_at
+                .min(self.data[self.y_data_editing_at].len());
+        }
+    }
+
+    pub fn render<B: Backend>(
+        &mut self,
+        f: &mut Frame<B>,
+        area: Rect,
+        _parent_state: ComponentState,
+    ) -> Result<()> {
+        let widget = List::new(
+            self.data
+                .iter()
+                .zip(self.enabled.iter())
+                .enumerate()
+                .map(|(i, (d, enabled))| {
+                    let checkbox = if *enabled { "[x] " } else { "[ ] " };
+                    let mut style = if *enabled {
+                        Style::default()
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    if let ComponentState::Focused = self.state {
+                        if i == self.y_data_editing_at {
+                            style = style.add_modifier(Modifier::BOLD);
+                        }
+                    }
+                    let content = vec![Spans::from(vec![
+                        Span::raw(checkbox),
+                        Span::styled(d.clone().into_iter().collect::<String>(), style),
+                    ])];
+                    ListItem::new(content)
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .style(match self.state {
+            ComponentState::Editing => Style::default().fg(Color::LightGreen),
+            ComponentState::Focused => Style::default().fg(Color::Green),
+            _ => Style::default(),
+        })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(keymap::get().title(Action::FocusHeaders, "HEADER")),
+        );
+
+        f.render_widget(widget, area);
+
+        if let ComponentState::Editing = self.state {
+            f.set_cursor(
+                area.x + 1 + CHECKBOX_WIDTH + self.x_cursor_postion(),
+                area.y + 1 + self.y_cursor_postion(),
+            )
+        }
+        Ok(())
+    }
+
+    fn x_cursor_postion(&self) -> u16 {
+        self.data[self.y_data_editing_at][..self.x_data_editing_at]
+            .iter()
+            .fold(0, |acc, c| acc + c.width().unwrap() as u16)
+    }
+
+    fn y_cursor_postion(&self) -> u16 {
+        self.y_data_editing_at as u16
+    }
+
+    pub fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    pub fn get_data(&self) -> Vec<HeaderData> {
+        self.data
+            .iter()
+            .zip(self.enabled.iter())
+            .filter_map(|(d, enabled)| {
+                let line = d.iter().collect::<String>();
+                let (name, value) = line.split_once(':')?;
+                let name = name.trim();
+                if name.is_empty() {
+                    None
+                } else {
+                    Some(HeaderData {
+                        name: name.to_string(),
+                        value: value.trim().to_string(),
+                        enabled: *enabled,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    pub fn set_data(&mut self, data: &[HeaderData]) {
+        self.data = data
+            .iter()
+            .map(|h| format!("{}: {}", h.name, h.value).chars().collect())
+            .collect();
+        self.enabled = data.iter().map(|h| h.enabled).collect();
+        if self.data.is_empty() {
+            self.data.push(vec![]);
+            self.enabled.push(true);
+        }
+        self.x_data_editing_at = 0;
+        self.y_data_editing_at = 0;
+    }
+}
@@ -1,32 +1,52 @@
-use crate::{components::ComponentState, event::EventSender, ui::default_key_handle};
+use crate::{
+    components::ComponentState,
+    event::EventSender,
+    keymap::{self, Action},
+    ui::default_key_handle,
+};
 use anyhow::Result;
 use termion::event::Key;
 use tui::{
     backend::Backend,
     layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, Borders, Paragraph},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
     Frame,
 };
+use unicode_width::UnicodeWidthChar;
 
-const METHODS: [reqwest::Method; 4] = [
+const METHODS: [reqwest::Method; 9] = [
     reqwest::Method::GET,
     reqwest::Method::POST,
     reqwest::Method::PUT,
+    reqwest::Method::PATCH,
     reqwest::Method::DELETE,
+    reqwest::Method::HEAD,
+    reqwest::Method::OPTIONS,
+    reqwest::Method::TRACE,
+    reqwest::Method::CONNECT,
 ];
 
+const CUSTOM_LABEL: &str = "Custom: ";
+
 pub struct Method {
-    data: &'static reqwest::Method,
+    data: reqwest::Method,
     selected_method_index: usize,
+    custom: Vec<char>,
+    x_custom_editing_at: usize,
+    area: Rect,
     state: ComponentState,
 }
 
 impl Default for Method {
     fn default() -> Self {
         Self {
-            data: &METHODS[0],
+            data: METHODS[0].clone(),
             selected_method_index: 0,
+            custom: vec![],
+            x_custom_editing_at: 0,
+            area: Rect::default(),
             state: ComponentState::UnFocused,
         }
     }
@@ -37,12 +57,51 @@ impl Method {
         match self.state {
             ComponentState::Focused => match k {
                 Key::Char('\n') => {
-                    self.selected_method_index = (self.selected_method_index + 1) % METHODS.len();
-                    self.data = &METHODS[self.selected_method_index];
+                    self.custom.clear();
+                    self.x_custom_editing_at = 0;
+                    self.state = ComponentState::Editing;
                 }
                 _ => default_key_handle(k, event_sender)?,
             },
             ComponentState::Editing => match k {
+                Key::Char('\n') => {
+                    if self.custom.is_empty() {
+                        self.data = METHODS[self.selected_method_index].clone();
+                        self.state = ComponentState::Focused;
+                    } else if let Ok(method) = reqwest::Method::from_bytes(
+                        self.custom.iter().collect::<String>().as_bytes(),
+                    ) {
+                        self.data = method;
+                        self.state = ComponentState::Focused;
+                    }
+                }
+                Key::Up => {
+                    self.selected_method_index =
+                        (self.selected_method_index + METHODS.len() - 1) % METHODS.len();
+                }
+                Key::Down => {
+                    self.selected_method_index = (self.selected_method_index + 1) % METHODS.len();
+                }
+                Key::Char(c) if c.is_ascii_graphic() => {
+                    self.custom.insert(self.x_custom_editing_at, c);
+                    self.x_custom_editing_at += 1;
+                }
+                Key::Backspace => {
+                    if self.x_custom_editing_at > 0 {
+                        self.custom.remove(self.x_custom_editing_at - 1);
+                        self.x_custom_editing_at -= 1;
+                    }
+                }
+                Key::Left => {
+                    if self.x_custom_editing_at > 0 {
+                        self.x_custom_editing_at -= 1;
+                    }
+                }
+                Key::Right => {
+                    if self.x_custom_editing_at < self.custom.len() {
+                        self.x_custom_editing_at += 1;
+                    }
+                }
                 Key::Esc => {
                     self.state = ComponentState::Focused;
                 }
@@ -66,13 +125,72 @@ impl Method {
                 ComponentState::Focused => Style::default().fg(Color::Green),
                 _ => Style::default(),
             })
-            .block(Block::default().borders(Borders::ALL).title("[M]Method"));
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(keymap::get().title(Action::FocusMethod, "Method")),
+            );
 
         f.render_widget(widget, area);
+        self.area = area;
 
         Ok(())
     }
 
+    pub fn render_popup<B: Backend>(&mut self, f: &mut Frame<B>) -> Result<()> {
+        if let ComponentState::Editing = self.state {
+            let frame_area = f.size();
+            let y = (self.area.y + self.area.height).min(frame_area.height);
+            let area = Rect {
+                x: self.area.x,
+                y,
+                width: 24.min(frame_area.width.saturating_sub(self.area.x)),
+                height: (METHODS.len() as u16 + 3).min(frame_area.height - y),
+            };
+
+            let mut items = METHODS
+                .iter()
+                .enumerate()
+                .map(|(i, method)| {
+                    let style = if i == self.selected_method_index && self.custom.is_empty() {
+                        Style::default()
+                            .fg(Color::LightGreen)
+                            .add_modifier(Modifier::REVERSED)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(Spans::from(Span::styled(method.as_str(), style)))
+                })
+                .collect::<Vec<ListItem>>();
+            items.push(ListItem::new(Spans::from(vec![
+                Span::styled(CUSTOM_LABEL, Style::default().fg(Color::DarkGray)),
+                Span::raw(self.custom.iter().collect::<String>()),
+            ])));
+
+            let widget = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::LightGreen))
+                    .title("Method"),
+            );
+
+            f.render_widget(Clear, area);
+            f.render_widget(widget, area);
+            f.set_cursor(
+                area.x + 1 + CUSTOM_LABEL.len() as u16 + self.x_cursor_postion(),
+                area.y + 1 + METHODS.len() as u16,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn x_cursor_postion(&self) -> u16 {
+        self.custom[..self.x_custom_editing_at]
+            .iter()
+            .fold(0, |acc, c| acc + c.width().unwrap() as u16)
+    }
+
     pub fn set_state(&mut self, state: ComponentState) {
         self.state = state;
     }
@@ -84,4 +202,11 @@ impl Method {
     pub fn get_data(&self) -> reqwest::Method {
         self.data.to_owned()
     }
+
+    pub fn set_data(&mut self, data: &str) {
+        if let Ok(method) = reqwest::Method::from_bytes(data.as_bytes()) {
+            self.selected_method_index = METHODS.iter().position(|m| *m == method).unwrap_or(0);
+            self.data = method;
+        }
+    }
 }
@@ -1,8 +1,12 @@
+mod auth;
 mod body;
+mod header;
 mod method;
 mod query;
 mod url;
+use auth::Auth;
 use body::Body;
+use header::Header;
 use method::Method;
 use query::Query;
 use url::Url;
@@ -11,6 +15,7 @@ use tui::layout::{Constraint, Direction, Layout};
 
 use crate::components::{Component, ComponentState};
 use crate::event::EventSender;
+use crate::request_data::RequestData;
 
 use anyhow::Result;
 use termion::event::Key;
@@ -20,16 +25,20 @@ pub struct Request {
     pub method: Method,
     pub url: Url,
     pub query: Query,
+    pub header: Header,
+    pub auth: Auth,
     pub body: Body,
     state: ComponentState,
 }
 
-impl<'a> Default for Request {
+impl Default for Request {
     fn default() -> Self {
         Self {
             method: Method::default(),
             url: Url::default(),
             query: Query::default(),
+            header: Header::default(),
+            auth: Auth::default(),
             body: Body::default(),
             state: ComponentState::Focused,
         }
@@ -46,19 +55,35 @@ impl Request {
         self.url.set_state(ComponentState::UnFocused);
         self.method.set_state(ComponentState::UnFocused);
         self.query.set_state(ComponentState::UnFocused);
+        self.header.set_state(ComponentState::UnFocused);
+        self.auth.set_state(ComponentState::UnFocused);
         self.body.set_state(ComponentState::UnFocused);
     }
 
-    pub fn get_method(&self) -> reqwest::Method {
-        self.method.get_data()
+    pub fn render_popup<B: Backend>(&mut self, f: &mut Frame<B>) -> Result<()> {
+        self.method.render_popup(f)
     }
 
-    pub fn get_url(&self) -> String {
-        self.url.get_data()
+    pub fn get_data(&self) -> RequestData {
+        RequestData {
+            method: self.method.get_data().to_string(),
+            url: self.url.get_data(),
+            headers: self.header.get_data(),
+            auth: self.auth.get_data(),
+            body: self.body.get_data(),
+            insecure: self.url.is_insecure(),
+        }
     }
 
-    pub fn get_body(&self) -> String {
-        self.body.get_data()
+    pub fn set_data(&mut self, data: &RequestData) {
+        self.method.set_data(&data.method);
+        self.url.set_data(&data.url);
+        self.url.set_insecure(data.insecure);
+        self.query
+            .set_data(&data.query().chars().collect::<Vec<char>>());
+        self.header.set_data(&data.headers);
+        self.auth.set_data(&data.auth);
+        self.body.set_data(&data.body);
     }
 }
 
@@ -70,6 +95,10 @@ impl Component for Request {
             self.url.key_handle(k, event_sender)?;
         } else if self.query.is_focused() {
             self.query.key_handle(k, event_sender)?;
+        } else if self.header.is_focused() {
+            self.header.key_handle(k, event_sender)?;
+        } else if self.auth.is_focused() {
+            self.auth.key_handle(k, event_sender)?;
         } else if self.body.is_focused() {
             self.body.key_handle(k, event_sender)?;
         }
@@ -96,7 +125,7 @@ impl Component for Request {
             (chunks[0], chunks[1])
         };
 
-        let (query_area, body_area) = {
+        let (query_and_header_area, auth_and_body_area) = {
             let chunks = Layout::default()
                 .margin(0)
                 .direction(Direction::Horizontal)
@@ -105,9 +134,29 @@ impl Component for Request {
             (chunks[0], chunks[1])
         };
 
+        let (query_area, header_area) = {
+            let chunks = Layout::default()
+                .margin(0)
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                .split(query_and_header_area);
+            (chunks[0], chunks[1])
+        };
+
+        let (auth_area, body_area) = {
+            let chunks = Layout::default()
+                .margin(0)
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(5), Constraint::Min(1)].as_ref())
+                .split(auth_and_body_area);
+            (chunks[0], chunks[1])
+        };
+
         self.url.render(f, url_area, self.state)?;
         self.method.render(f, method_area, self.state)?;
         self.query.render(f, query_area, self.state)?;
+        self.header.render(f, header_area, self.state)?;
+        self.auth.render(f, auth_area, self.state)?;
         self.body.render(f, body_area, self.state)?;
 
         Ok(())
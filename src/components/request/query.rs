@@ -13,6 +13,7 @@ use unicode_width::UnicodeWidthChar;
 use crate::{
     components::ComponentState,
     event::{Event, EventSender},
+    keymap::{self, Action},
     ui::default_key_handle,
 };
 
@@ -119,16 +120,19 @@ impl Query {
             ComponentState::Focused => Style::default().fg(Color::Green),
             _ => Style::default(),
         })
-        .block(Block::default().borders(Borders::ALL).title("[Q]QUERY"));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(keymap::get().title(Action::FocusQuery, "QUERY")),
+        );
 
         f.render_widget(widget, area);
 
-        match self.state {
-            ComponentState::Editing => f.set_cursor(
+        if let ComponentState::Editing = self.state {
+            f.set_cursor(
                 area.x + 1 + self.x_cursor_postion(),
                 area.y + 1 + self.y_cursor_postion(),
-            ),
-            _ => {}
+            )
         }
         Ok(())
     }
@@ -151,7 +155,7 @@ impl Query {
         self.state = state;
     }
 
-    pub fn set_data(&mut self, data: &Vec<char>) {
+    pub fn set_data(&mut self, data: &[char]) {
         let new_data = data
             .split(|c| *c == '&')
             .map(|v| v.to_vec())
@@ -4,6 +4,7 @@ use tui::{
     backend::Backend,
     layout::Rect,
     style::{Color, Style},
+    text::{Span, Spans},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
@@ -12,12 +13,14 @@ use unicode_width::UnicodeWidthChar;
 use crate::{
     components::ComponentState,
     event::{Event, EventSender},
+    keymap::{self, Action},
     ui::default_key_handle,
 };
 
 pub struct Url {
     data: Vec<char>,
     x_data_editing_at: usize,
+    insecure: bool,
     state: ComponentState,
 }
 
@@ -26,6 +29,7 @@ impl Default for Url {
         Self {
             data: vec![],
             x_data_editing_at: 0,
+            insecure: false,
             state: ComponentState::Focused,
         }
     }
@@ -38,6 +42,10 @@ impl Url {
                 Key::Char('\n') => {
                     self.state = ComponentState::Editing;
                 }
+                _ if keymap::get().is(Action::ToggleInsecure, k) => self.insecure = !self.insecure,
+                _ if keymap::get().is(Action::Copy, k) => {
+                    event_sender.send(Event::Copy(self.get_data()))
+                }
                 _ => default_key_handle(k, event_sender)?,
             },
             ComponentState::Editing => match k {
@@ -86,30 +94,40 @@ impl Url {
         area: Rect,
         _parent_state: ComponentState,
     ) -> Result<()> {
+        let keymap = keymap::get();
+        let title = keymap.title(Action::FocusUrl, "URL");
         let widget = Paragraph::new(self.data.clone().into_iter().collect::<String>())
             .style(match self.state {
                 ComponentState::Editing => Style::default().fg(Color::LightGreen),
                 ComponentState::Focused => Style::default().fg(Color::Green),
                 _ => Style::default(),
             })
-            .block(Block::default().borders(Borders::ALL).title("[U]URL"));
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(if self.insecure {
+                        Spans::from(vec![
+                            Span::raw(format!("{} ", title)),
+                            Span::styled(
+                                keymap.title(Action::ToggleInsecure, "INSECURE"),
+                                Style::default().fg(Color::Red),
+                            ),
+                        ])
+                    } else {
+                        Spans::from(title)
+                    }),
+            );
 
         f.render_widget(widget, area);
 
-        match self.state {
-            ComponentState::Editing => {
-                f.set_cursor(area.x + 1 + self.x_cursor_postion(), area.y + 1)
-            }
-            _ => {}
+        if let ComponentState::Editing = self.state {
+            f.set_cursor(area.x + 1 + self.x_cursor_postion(), area.y + 1)
         }
         Ok(())
     }
 
     pub fn get_query(&self) -> Option<Vec<char>> {
-        let split_at = match self.data.iter().position(|&c| c == '?') {
-            None => return None,
-            Some(i) => i,
-        };
+        let split_at = self.data.iter().position(|&c| c == '?')?;
         let (_, query) = self.data.split_at(split_at);
         if query.len() == 1 {
             Some(vec![])
@@ -124,9 +142,9 @@ impl Url {
         }
     }
 
-    pub fn set_query(&mut self, query: &Vec<char>) {
+    pub fn set_query(&mut self, query: &[char]) {
         if let Some(old_query) = self.get_query() {
-            if &old_query == query {
+            if old_query == query {
                 return;
             }
         }
@@ -142,10 +160,23 @@ impl Url {
         self.x_data_editing_at = self.data.len();
     }
 
+    pub fn is_insecure(&self) -> bool {
+        self.insecure
+    }
+
+    pub fn set_insecure(&mut self, insecure: bool) {
+        self.insecure = insecure;
+    }
+
     pub fn get_data(&self) -> String {
         self.data.iter().collect()
     }
 
+    pub fn set_data(&mut self, data: &str) {
+        self.data = data.chars().collect();
+        self.x_data_editing_at = self.data.len();
+    }
+
     pub fn set_state(&mut self, state: ComponentState) {
         self.state = state;
     }
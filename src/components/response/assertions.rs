@@ -0,0 +1,118 @@
+use crate::{
+    assertion::AssertionResult,
+    components::ComponentState,
+    event::EventSender,
+    keymap::{self, Action},
+    ui::default_key_handle,
+};
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+pub struct Tests {
+    data: Vec<AssertionResult>,
+    data_display_from: usize,
+    state: ComponentState,
+}
+
+impl Default for Tests {
+    fn default() -> Self {
+        Self {
+            data: vec![],
+            data_display_from: 0,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl Tests {
+    pub fn set_data(&mut self, data: Vec<AssertionResult>) {
+        self.data = data;
+        self.data_display_from = 0;
+    }
+
+    pub fn summary(&self) -> Option<(usize, usize)> {
+        if self.data.is_empty() {
+            None
+        } else {
+            let passed = self.data.iter().filter(|result| result.passed).count();
+            Some((passed, self.data.len()))
+        }
+    }
+
+    pub fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        match k {
+            _ if keymap::get().is(Action::Down, k) => {
+                if self.data_display_from + 1 < self.data.len() {
+                    self.data_display_from += 1;
+                }
+            }
+            _ if keymap::get().is(Action::Up, k) => {
+                if self.data_display_from > 0 {
+                    self.data_display_from -= 1;
+                }
+            }
+            _ => default_key_handle(k, event_sender)?,
+        }
+
+        Ok(())
+    }
+
+    pub fn render<B: Backend>(
+        &mut self,
+        f: &mut Frame<B>,
+        area: Rect,
+        state: ComponentState,
+    ) -> Result<()> {
+        let title = match self.summary() {
+            Some((passed, total)) => format!("{}/{} passed", passed, total),
+            None => "no assertions".to_string(),
+        };
+        let widget = List::new(
+            self.data[self.data_display_from..]
+                .iter()
+                .map(|result| {
+                    let mut spans = if result.passed {
+                        vec![Span::styled("PASS ", Style::default().fg(Color::Green))]
+                    } else {
+                        vec![Span::styled("FAIL ", Style::default().fg(Color::Red))]
+                    };
+                    spans.push(Span::raw(result.description.clone()));
+                    if let Some(message) = &result.message {
+                        spans.push(Span::styled(
+                            format!("  ({})", message),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                    ListItem::new(Spans::from(spans))
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+        let widget = match state {
+            ComponentState::Focused => widget.style(Style::default().fg(Color::Green)),
+            _ => widget,
+        };
+
+        f.render_widget(widget, area);
+
+        Ok(())
+    }
+
+    pub fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+}
@@ -1,19 +1,42 @@
+use std::fs;
+use std::path::PathBuf;
+
 use anyhow::Result;
 use termion::event::Key;
 use tui::{
     backend::Backend,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
-use crate::{components::ComponentState, event::EventSender, ui::default_key_handle};
+use crate::{
+    components::{line_input::LineInput, ComponentState},
+    event::{Event, EventSender},
+    keymap::{self, Action},
+    renderer::{self, BodyKind},
+    ui::default_key_handle,
+};
+
+enum SavePrompt {
+    Path,
+    Overwrite(PathBuf),
+}
 
 pub struct Body {
     data: Vec<String>,
+    raw: Vec<u8>,
+    raw_data: Vec<String>,
+    file_name: String,
+    save_prompt: Option<SavePrompt>,
+    save_input: LineInput,
+    kind: Option<BodyKind>,
+    size: usize,
+    show_raw: bool,
     data_display_from: usize,
+    page_height: usize,
     state: ComponentState,
 }
 
@@ -21,29 +44,152 @@ impl Default for Body {
     fn default() -> Self {
         Self {
             data: vec![],
+            raw: vec![],
+            raw_data: vec![],
+            file_name: String::new(),
+            save_prompt: None,
+            save_input: LineInput::default(),
+            kind: None,
+            size: 0,
+            show_raw: false,
             data_display_from: 0,
+            page_height: 0,
             state: ComponentState::Focused,
         }
     }
 }
 
 impl Body {
-    pub fn set_data(&mut self, data: Vec<String>) {
+    pub fn set_data(&mut self, data: Vec<String>, raw: Vec<u8>, kind: BodyKind, file_name: String) {
         self.data = data;
+        self.raw_data = String::from_utf8_lossy(&raw)
+            .lines()
+            .map(|s| s.to_string())
+            .collect();
+        self.kind = Some(kind);
+        self.size = raw.len();
+        self.raw = raw;
+        self.file_name = file_name;
+        self.data_display_from = 0;
+    }
+
+    fn save(&mut self, path: PathBuf, event_sender: &EventSender) {
+        self.save_prompt = None;
+        let result = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&path, &self.raw));
+        event_sender.send(Event::Notify(match result {
+            Ok(()) => Ok(format!(
+                "Saved {} bytes to {}",
+                self.raw.len(),
+                path.display()
+            )),
+            Err(err) => Err(format!("Save failed: {}", err)),
+        }));
+    }
+
+    fn submit_save_path(&mut self, event_sender: &EventSender) {
+        let input = self.save_input.get_data();
+        let input = input.trim();
+        if input.is_empty() {
+            return;
+        }
+        let path = match input.strip_prefix("~/").zip(dirs::home_dir()) {
+            Some((rest, home)) => home.join(rest),
+            None => PathBuf::from(input),
+        };
+        if path.exists() {
+            self.save_prompt = Some(SavePrompt::Overwrite(path));
+        } else {
+            self.save(path, event_sender);
+        }
+    }
+
+    fn save_prompt_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        match (self.save_prompt.take(), k) {
+            (Some(SavePrompt::Overwrite(path)), _) if keymap::get().is(Action::Confirm, k) => {
+                self.save(path, &event_sender)
+            }
+            (Some(SavePrompt::Path), Key::Char('\n')) => {
+                self.save_prompt = Some(SavePrompt::Path);
+                self.submit_save_path(&event_sender);
+            }
+            (Some(SavePrompt::Path), Key::Esc)
+            | (Some(SavePrompt::Overwrite(_)), _)
+            | (None, _) => {}
+            (Some(SavePrompt::Path), _) => {
+                self.save_prompt = Some(SavePrompt::Path);
+                if !self.save_input.key_handle(k) {
+                    default_key_handle(k, event_sender)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_binary(&self) -> bool {
+        self.kind == Some(BodyKind::Binary)
+    }
+
+    fn displayed_data(&self) -> &Vec<String> {
+        if self.show_raw && !self.is_binary() {
+            &self.raw_data
+        } else {
+            &self.data
+        }
     }
 
     pub fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        if self.save_prompt.is_some() {
+            return self.save_prompt_handle(k, event_sender);
+        }
+
         match k {
-            Key::Char('j') => {
-                if self.data_display_from + 1 < self.data.len() {
+            _ if keymap::get().is(Action::Down, k) => {
+                if self.data_display_from + 1 < self.displayed_data().len() {
                     self.data_display_from += 1;
                 }
             }
-            Key::Char('k') => {
+            _ if keymap::get().is(Action::Up, k) => {
                 if self.data_display_from > 0 {
                     self.data_display_from -= 1;
                 }
             }
+            _ if keymap::get().is(Action::PageDown, k) => {
+                self.data_display_from = (self.data_display_from + self.page_height.max(1))
+                    .min(self.displayed_data().len().saturating_sub(1));
+            }
+            _ if keymap::get().is(Action::PageUp, k) => {
+                self.data_display_from = self
+                    .data_display_from
+                    .saturating_sub(self.page_height.max(1));
+            }
+            _ if keymap::get().is(Action::Top, k) => {
+                self.data_display_from = 0;
+            }
+            _ if keymap::get().is(Action::Bottom, k) => {
+                self.data_display_from = self.displayed_data().len().saturating_sub(1);
+            }
+            _ if keymap::get().is(Action::ToggleRaw, k) => {
+                self.show_raw = !self.show_raw;
+                self.data_display_from = 0;
+            }
+            _ if keymap::get().is(Action::Copy, k) => {
+                event_sender.send(Event::Copy(self.displayed_data().join("\n")));
+            }
+            _ if keymap::get().is(Action::SaveBody, k) => {
+                if self.kind.is_some() {
+                    self.save_input.set_data(&self.file_name);
+                    self.save_prompt = Some(SavePrompt::Path);
+                }
+            }
+            _ if keymap::get().is(Action::CopyLine, k) => {
+                if let Some(line) = self.displayed_data().get(self.data_display_from) {
+                    event_sender.send(Event::Copy(line.clone()));
+                }
+            }
             _ => default_key_handle(k, event_sender)?,
         }
         Ok(())
@@ -55,8 +201,28 @@ impl Body {
         area: Rect,
         parent_state: ComponentState,
     ) -> Result<()> {
+        self.page_height = area.height.saturating_sub(2) as usize;
+
+        let title = match self.kind {
+            Some(BodyKind::Binary) => format!(
+                "{} {} bytes (offset {:08x})",
+                BodyKind::Binary.as_str(),
+                self.size,
+                self.data_display_from * renderer::HEX_BYTES_PER_LINE
+            ),
+            Some(kind) => format!(
+                "{} {} bytes {}",
+                kind.as_str(),
+                self.size,
+                keymap::get().title(
+                    Action::ToggleRaw,
+                    if self.show_raw { "RAW" } else { "PRETTY" }
+                )
+            ),
+            None => String::new(),
+        };
         let widget = List::new(
-            self.data[self.data_display_from..]
+            self.displayed_data()[self.data_display_from..]
                 .iter()
                 .map(|d| {
                     let content = vec![Spans::from(Span::raw(d))];
@@ -64,14 +230,52 @@ impl Body {
                 })
                 .collect::<Vec<ListItem>>(),
         )
-        .block(Block::default().borders(Borders::ALL));
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .block(Block::default().borders(Borders::ALL).title(title));
 
+        let mut list_state = ListState::default();
         let widget = match parent_state {
-            ComponentState::Focused => widget.style(Style::default().fg(Color::Green)),
+            ComponentState::Focused => {
+                list_state.select(Some(0));
+                widget.style(Style::default().fg(Color::Green))
+            }
             _ => widget,
         };
 
-        f.render_widget(widget, area);
+        f.render_stateful_widget(widget, area, &mut list_state);
+
+        if let Some(prompt) = &self.save_prompt {
+            let prompt_area = Rect {
+                x: area.x + 1,
+                y: area.y + area.height.saturating_sub(2),
+                width: area.width.saturating_sub(2),
+                height: 1.min(area.height),
+            };
+            let (label, input) = match prompt {
+                SavePrompt::Path => ("Save to: ".to_string(), self.save_input.get_data()),
+                SavePrompt::Overwrite(path) => (
+                    format!(
+                        "Overwrite {}? ({}/n) ",
+                        path.display(),
+                        keymap::get().label(Action::Confirm)
+                    ),
+                    String::new(),
+                ),
+            };
+            let widget = Paragraph::new(Spans::from(vec![
+                Span::raw(label.clone()),
+                Span::raw(input),
+            ]))
+            .style(Style::default().fg(Color::LightGreen));
+            f.render_widget(Clear, prompt_area);
+            f.render_widget(widget, prompt_area);
+            if let SavePrompt::Path = prompt {
+                f.set_cursor(
+                    prompt_area.x + label.len() as u16 + self.save_input.x_cursor_postion(),
+                    prompt_area.y,
+                );
+            }
+        }
 
         Ok(())
     }
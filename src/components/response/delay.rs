@@ -10,21 +10,20 @@ use tui::{
     Frame,
 };
 
+#[derive(Default)]
 pub struct Delay {
     data: Option<Duration>,
 }
 
-impl Default for Delay {
-    fn default() -> Self {
-        Self { data: None }
-    }
-}
-
 impl Delay {
     pub fn set_data(&mut self, data: Duration) {
         self.data = Some(data);
     }
 
+    pub fn clear(&mut self) {
+        self.data = None;
+    }
+
     pub fn render<B: Backend>(
         &mut self,
         f: &mut Frame<B>,
@@ -0,0 +1,58 @@
+use crate::{components::ComponentState, http_request};
+
+use anyhow::Result;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+#[derive(Default)]
+pub struct Error {
+    data: Option<http_request::Error>,
+}
+
+impl Error {
+    pub fn set_data(&mut self, data: http_request::Error) {
+        self.data = Some(data);
+    }
+
+    pub fn clear(&mut self) {
+        self.data = None;
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.data.is_some()
+    }
+
+    pub fn render<B: Backend>(
+        &mut self,
+        f: &mut Frame<B>,
+        area: Rect,
+        _parent_state: ComponentState,
+    ) -> Result<()> {
+        if let Some(data) = &self.data {
+            let widget = Paragraph::new(vec![
+                Spans::from(Span::styled(
+                    data.kind.as_str(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                Spans::from(Span::raw(data.message.clone())),
+            ])
+            .style(Style::default().fg(Color::Magenta))
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Magenta)),
+            );
+
+            f.render_widget(widget, area);
+        }
+
+        Ok(())
+    }
+}
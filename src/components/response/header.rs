@@ -1,13 +1,18 @@
-use crate::{components::ComponentState, event::EventSender, ui::default_key_handle};
+use crate::{
+    components::ComponentState,
+    event::{Event, EventSender},
+    keymap::{self, Action},
+    ui::default_key_handle,
+};
 
 use anyhow::Result;
 use termion::event::Key;
 use tui::{
     backend::Backend,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
 
@@ -30,20 +35,26 @@ impl Default for Header {
 impl Header {
     pub fn set_data(&mut self, data: Vec<(String, String)>) {
         self.data = data;
+        self.data_display_from = 0;
     }
 
     pub fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
         match k {
-            Key::Char('j') => {
+            _ if keymap::get().is(Action::Down, k) => {
                 if self.data_display_from + 1 < self.data.len() {
                     self.data_display_from += 1;
                 }
             }
-            Key::Char('k') => {
+            _ if keymap::get().is(Action::Up, k) => {
                 if self.data_display_from > 0 {
                     self.data_display_from -= 1;
                 }
             }
+            _ if keymap::get().is(Action::Copy, k) => {
+                if let Some((_, value)) = self.data.get(self.data_display_from) {
+                    event_sender.send(Event::Copy(value.clone()));
+                }
+            }
             _ => default_key_handle(k, event_sender)?,
         }
 
@@ -65,14 +76,19 @@ impl Header {
                 })
                 .collect::<Vec<ListItem>>(),
         )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
         .block(Block::default().borders(Borders::ALL));
 
+        let mut list_state = ListState::default();
         let widget = match state {
-            ComponentState::Focused => widget.style(Style::default().fg(Color::Green)),
+            ComponentState::Focused => {
+                list_state.select(Some(0));
+                widget.style(Style::default().fg(Color::Green))
+            }
             _ => widget,
         };
 
-        f.render_widget(widget, area);
+        f.render_stateful_widget(widget, area, &mut list_state);
 
         Ok(())
     }
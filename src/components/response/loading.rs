@@ -0,0 +1,64 @@
+use std::time::Instant;
+
+use crate::components::ComponentState;
+use crate::keymap::{self, Action};
+
+use anyhow::Result;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+
+#[derive(Default)]
+pub struct Loading {
+    started_at: Option<Instant>,
+}
+
+impl Loading {
+    pub fn start(&mut self) {
+        self.started_at = Some(Instant::now());
+    }
+
+    pub fn stop(&mut self) {
+        self.started_at = None;
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    pub fn render<B: Backend>(
+        &mut self,
+        f: &mut Frame<B>,
+        area: Rect,
+        _parent_state: ComponentState,
+    ) -> Result<()> {
+        if let Some(started_at) = self.started_at {
+            let elapsed = started_at.elapsed();
+            let spinner = SPINNER[(elapsed.as_millis() / 250) as usize % SPINNER.len()];
+            let widget = Paragraph::new(vec![
+                Spans::from(Span::raw(format!(
+                    "{} Sending request... {:.1}s",
+                    spinner,
+                    elapsed.as_secs_f64()
+                ))),
+                Spans::from(Span::styled(
+                    format!("{}: cancel", keymap::get().label(Action::CancelRequest)),
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ])
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().borders(Borders::ALL));
+
+            f.render_widget(widget, area);
+        }
+
+        Ok(())
+    }
+}
@@ -1,15 +1,22 @@
+mod assertions;
 mod body;
 mod delay;
+mod error;
 mod header;
+mod loading;
 mod status;
 mod tab;
+use assertions::Tests;
 use body::Body;
 use delay::Delay;
+use error::Error;
 use header::Header;
+use loading::Loading;
 use status::Status;
 use tab::Tab;
 use tui::layout::{Constraint, Direction, Layout};
 
+use crate::assertion::{self, Assertion};
 use crate::components::{Component, ComponentState};
 use crate::event::EventSender;
 use crate::http_request;
@@ -22,14 +29,18 @@ use tui::{backend::Backend, layout::Rect, Frame};
 pub enum ResponseComponents {
     Body,
     Header,
+    Tests,
 }
 
 pub struct Response {
     tab: Tab,
     pub body: Body,
     pub header: Header,
+    pub tests: Tests,
     pub status: Status,
     pub delay: Delay,
+    pub error: Error,
+    pub loading: Loading,
     state: ComponentState,
 }
 
@@ -39,19 +50,41 @@ impl Default for Response {
             tab: Tab::default(),
             body: Body::default(),
             header: Header::default(),
+            tests: Tests::default(),
             status: Status::default(),
             delay: Delay::default(),
+            error: Error::default(),
+            loading: Loading::default(),
             state: ComponentState::UnFocused,
         }
     }
 }
 
 impl Response {
-    pub fn set_data(&mut self, resp: http_request::Response) {
-        self.body.set_data(resp.body);
+    pub fn set_data(&mut self, resp: http_request::Response, assertions: &[Assertion]) {
+        self.tests
+            .set_data(assertion::evaluate_all(assertions, &resp));
+        let file_name = resp.file_name();
+        self.body
+            .set_data(resp.body, resp.raw_body, resp.body_kind, file_name);
         self.header.set_data(resp.header);
         self.status.set_data(resp.status);
         self.delay.set_data(resp.delay);
+        self.error.clear();
+        self.loading.stop();
+    }
+
+    pub fn set_error(&mut self, err: http_request::Error) {
+        self.error.set_data(err);
+        self.status.clear();
+        self.delay.clear();
+        self.loading.stop();
+    }
+
+    pub fn set_loading(&mut self) {
+        self.error.clear();
+        self.tests.set_data(vec![]);
+        self.loading.start();
     }
 
     pub fn is_focused(&self) -> bool {
@@ -62,6 +95,7 @@ impl Response {
         self.set_state(ComponentState::UnFocused);
         self.body.set_state(ComponentState::UnFocused);
         self.header.set_state(ComponentState::UnFocused);
+        self.tests.set_state(ComponentState::UnFocused);
     }
 }
 
@@ -71,12 +105,15 @@ impl Component for Response {
             self.body.key_handle(k, event_sender)?;
         } else if self.header.is_focused() {
             self.header.key_handle(k, event_sender)?;
+        } else if self.tests.is_focused() {
+            self.tests.key_handle(k, event_sender)?;
         }
 
         Ok(())
     }
 
     fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let tests_summary = self.tests.summary();
         let (tab_area, main_area, status_area, delay_area) = {
             let chunks = Layout::default()
                 .margin(0)
@@ -106,12 +143,36 @@ impl Component for Response {
 
         if self.body.is_focused() {
             self.body.render(f, main_area, self.state)?;
-            self.tab
-                .render(f, tab_area, self.state, ResponseComponents::Body)?;
+            self.tab.render(
+                f,
+                tab_area,
+                self.state,
+                ResponseComponents::Body,
+                tests_summary,
+            )?;
         } else if self.header.is_focused() {
             self.header.render(f, main_area, self.state)?;
-            self.tab
-                .render(f, tab_area, self.state, ResponseComponents::Header)?;
+            self.tab.render(
+                f,
+                tab_area,
+                self.state,
+                ResponseComponents::Header,
+                tests_summary,
+            )?;
+        } else if self.tests.is_focused() {
+            self.tests.render(f, main_area, self.state)?;
+            self.tab.render(
+                f,
+                tab_area,
+                self.state,
+                ResponseComponents::Tests,
+                tests_summary,
+            )?;
+        }
+        if self.loading.is_loading() {
+            self.loading.render(f, main_area, self.state)?;
+        } else if self.error.is_set() {
+            self.error.render(f, main_area, self.state)?;
         }
         self.status.render(f, status_area, self.state)?;
         self.delay.render(f, delay_area, self.state)?;
@@ -10,21 +10,20 @@ use tui::{
     Frame,
 };
 
+#[derive(Default)]
 pub struct Status {
     data: Option<StatusCode>,
 }
 
-impl Default for Status {
-    fn default() -> Self {
-        Self { data: None }
-    }
-}
-
 impl Status {
     pub fn set_data(&mut self, data: StatusCode) {
         self.data = Some(data);
     }
 
+    pub fn clear(&mut self) {
+        self.data = None;
+    }
+
     pub fn render<B: Backend>(
         &mut self,
         f: &mut Frame<B>,
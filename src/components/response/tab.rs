@@ -1,4 +1,5 @@
 use crate::components::{response::ResponseComponents, ComponentState};
+use crate::keymap::{self, Action};
 
 use anyhow::Result;
 use tui::{
@@ -9,14 +10,9 @@ use tui::{
     Frame,
 };
 
+#[derive(Default)]
 pub struct Tab {}
 
-impl Default for Tab {
-    fn default() -> Self {
-        Self {}
-    }
-}
-
 impl Tab {
     pub fn render<B: Backend>(
         &mut self,
@@ -24,21 +20,44 @@ impl Tab {
         area: Rect,
         parent_state: ComponentState,
         selected_tab: ResponseComponents,
+        tests_summary: Option<(usize, usize)>,
     ) -> Result<()> {
-        let mut body_tab = Paragraph::new("[B]Body")
+        let keymap = keymap::get();
+        let tests_title = keymap.title(Action::FocusTests, "Tests");
+        let (tests_label, tests_color) = match tests_summary {
+            Some((passed, total)) if passed == total => (
+                format!("{} {}/{}", tests_title, passed, total),
+                Color::Green,
+            ),
+            Some((passed, total)) => (format!("{} {}/{}", tests_title, passed, total), Color::Red),
+            None => (tests_title, Color::DarkGray),
+        };
+        let body_label = keymap.title(Action::FocusResponseBody, "Body");
+        let header_label = keymap.title(Action::FocusResponseHeaders, "Header");
+        let body_width = body_label.chars().count() as u16 + 3;
+        let header_width = header_label.chars().count() as u16 + 4;
+        let mut body_tab = Paragraph::new(body_label)
             .style(Style::default().fg(Color::DarkGray))
             .block(
                 Block::default()
                     .borders(Borders::TOP.union(Borders::LEFT.union(Borders::RIGHT)))
                     .border_style(Style::default().fg(Color::DarkGray)),
             );
-        let mut header_tab = Paragraph::new("[H]Header")
+        let mut header_tab = Paragraph::new(header_label)
             .style(Style::default().fg(Color::DarkGray))
             .block(
                 Block::default()
                     .borders(Borders::TOP.union(Borders::LEFT.union(Borders::RIGHT)))
                     .border_style(Style::default().fg(Color::DarkGray)),
             );
+        let tests_width = tests_label.chars().count() as u16 + 4;
+        let mut tests_tab = Paragraph::new(tests_label)
+            .style(Style::default().fg(tests_color))
+            .block(
+                Block::default()
+                    .borders(Borders::TOP.union(Borders::LEFT.union(Borders::RIGHT)))
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            );
         match selected_tab {
             ResponseComponents::Body => {
                 body_tab = body_tab.style(Style::default()).block(
@@ -52,26 +71,35 @@ impl Tab {
                         .borders(Borders::TOP.union(Borders::LEFT.union(Borders::RIGHT))),
                 )
             }
+            ResponseComponents::Tests => {
+                tests_tab = tests_tab.block(
+                    Block::default()
+                        .borders(Borders::TOP.union(Borders::LEFT.union(Borders::RIGHT))),
+                )
+            }
         }
 
-        match parent_state {
-            ComponentState::Focused => match selected_tab {
+        if let ComponentState::Focused = parent_state {
+            match selected_tab {
                 ResponseComponents::Body => {
                     body_tab = body_tab.style(Style::default().fg(Color::Green));
                 }
                 ResponseComponents::Header => {
                     header_tab = header_tab.style(Style::default().fg(Color::Green));
                 }
-            },
-            _ => {}
+                ResponseComponents::Tests => {
+                    tests_tab = tests_tab.style(Style::default().fg(Color::Green));
+                }
+            }
         }
 
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints(
                 [
-                    Constraint::Length(10),
-                    Constraint::Length(13),
+                    Constraint::Length(body_width),
+                    Constraint::Length(header_width),
+                    Constraint::Length(tests_width),
                     Constraint::Min(1),
                 ]
                 .as_ref(),
@@ -80,6 +108,7 @@ impl Tab {
 
         f.render_widget(body_tab, chunks[0]);
         f.render_widget(header_tab, chunks[1]);
+        f.render_widget(tests_tab, chunks[2]);
 
         Ok(())
     }
@@ -0,0 +1,53 @@
+use crate::components::{Component, ComponentState};
+use crate::event::EventSender;
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Spans,
+    widgets::Tabs,
+    Frame,
+};
+
+#[derive(Default)]
+pub struct TabBar {
+    titles: Vec<String>,
+    selected: usize,
+}
+
+impl TabBar {
+    pub fn set_tabs(&mut self, titles: Vec<String>, selected: usize) {
+        self.titles = titles;
+        self.selected = selected;
+    }
+}
+
+impl Component for TabBar {
+    fn key_handle(&mut self, _k: Key, _: EventSender) -> Result<()> {
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let tabs = Tabs::new(
+            self.titles
+                .iter()
+                .enumerate()
+                .map(|(i, title)| Spans::from(format!("{}: {}", i + 1, title)))
+                .collect(),
+        )
+        .select(self.selected)
+        .style(Style::default().fg(Color::DarkGray))
+        .highlight_style(
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::REVERSED),
+        );
+        f.render_widget(tabs, area);
+        Ok(())
+    }
+
+    fn set_state(&mut self, _state: ComponentState) {}
+}
@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+pub struct Toast {
+    message: Option<(String, Color, Instant)>,
+}
+
+impl Toast {
+    pub fn show(&mut self, message: String) {
+        self.message = Some((message, Color::Green, Instant::now()));
+    }
+
+    pub fn show_error(&mut self, message: String) {
+        self.message = Some((message, Color::Red, Instant::now()));
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        if let Some((message, color, shown_at)) = &self.message {
+            if shown_at.elapsed() > TOAST_DURATION {
+                self.message = None;
+                return Ok(());
+            }
+            let width = (message.chars().count() as u16 + 4).min(area.width);
+            let toast_area = Rect {
+                x: area.x + area.width - width,
+                y: area.y + area.height.saturating_sub(3),
+                width,
+                height: 3.min(area.height),
+            };
+            let widget = Paragraph::new(message.as_str())
+                .style(Style::default().fg(*color))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(*color)),
+                );
+            f.render_widget(Clear, toast_area);
+            f.render_widget(widget, toast_area);
+        }
+
+        Ok(())
+    }
+}
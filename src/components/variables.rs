@@ -0,0 +1,216 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::capture::Capture;
+use crate::components::{Component, ComponentPosition, ComponentState};
+use crate::event::{Event, EventSender};
+use crate::http_request;
+use crate::keymap::{self, Action};
+use crate::ui::default_key_handle;
+
+use anyhow::Result;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+pub struct Variables {
+    captured: BTreeMap<String, String>,
+    environment: BTreeMap<String, String>,
+    results: Vec<(String, Result<String, String>)>,
+    selected: usize,
+    state: ComponentState,
+}
+
+impl Default for Variables {
+    fn default() -> Self {
+        Self {
+            captured: BTreeMap::new(),
+            environment: BTreeMap::new(),
+            results: vec![],
+            selected: 0,
+            state: ComponentState::UnFocused,
+        }
+    }
+}
+
+impl Variables {
+    pub fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    pub fn captured(&self) -> &BTreeMap<String, String> {
+        &self.captured
+    }
+
+    pub fn set_environment(&mut self, variables: HashMap<String, String>) {
+        self.environment = variables.into_iter().collect();
+    }
+
+    pub fn capture(&mut self, captures: &[Capture], resp: &http_request::Response) {
+        self.results = captures
+            .iter()
+            .map(|capture| (capture.name.clone(), capture.extract(resp)))
+            .collect();
+        for (name, result) in &self.results {
+            if let Ok(value) = result {
+                self.captured.insert(name.clone(), value.clone());
+            }
+        }
+    }
+
+    fn rows(&self) -> Vec<(&String, &String, bool)> {
+        self.captured
+            .iter()
+            .map(|(name, value)| (name, value, true))
+            .chain(
+                self.environment
+                    .iter()
+                    .filter(|(name, _)| !self.captured.contains_key(*name))
+                    .map(|(name, value)| (name, value, false)),
+            )
+            .collect()
+    }
+}
+
+impl Component for Variables {
+    fn key_handle(&mut self, k: Key, event_sender: EventSender) -> Result<()> {
+        if let ComponentState::Focused = self.state {
+            match k {
+                _ if keymap::get().is(Action::Up, k) => {
+                    if self.selected > 0 {
+                        self.selected -= 1;
+                    }
+                }
+                _ if keymap::get().is(Action::Down, k) => {
+                    if self.selected + 1 < self.rows().len() {
+                        self.selected += 1;
+                    }
+                }
+                _ if keymap::get().is(Action::Delete, k) => {
+                    let name = self
+                        .rows()
+                        .get(self.selected)
+                        .filter(|(_, _, captured)| *captured)
+                        .map(|(name, _, _)| name.to_string());
+                    if let Some(name) = name {
+                        self.captured.remove(&name);
+                        self.selected = self.selected.min(self.rows().len().saturating_sub(1));
+                    }
+                }
+                _ if keymap::get().is(Action::ClearCaptured, k) => {
+                    self.captured.clear();
+                    self.results.clear();
+                    self.selected = 0;
+                }
+                Key::Esc => {
+                    event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
+                }
+                _ => default_key_handle(k, event_sender)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) -> Result<()> {
+        let keymap = keymap::get();
+        let (list_area, results_area, help_area) = {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Min(1),
+                        Constraint::Length(self.results.len() as u16 + 2),
+                        Constraint::Length(1),
+                    ]
+                    .as_ref(),
+                )
+                .split(area);
+            (chunks[0], chunks[1], chunks[2])
+        };
+
+        let rows = self.rows();
+        let name_width = rows
+            .iter()
+            .map(|(name, _, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        let items = rows
+            .iter()
+            .map(|(name, value, captured)| {
+                let (source, source_style) = if *captured {
+                    ("captured", Style::default().fg(Color::Yellow))
+                } else {
+                    ("env     ", Style::default().fg(Color::DarkGray))
+                };
+                ListItem::new(Spans::from(vec![
+                    Span::styled(format!("{} ", source), source_style),
+                    Span::styled(
+                        format!("{:<width$} ", name, width = name_width),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(value.to_string()),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+        let list = List::new(items)
+            .style(match self.state {
+                ComponentState::Focused => Style::default().fg(Color::Green),
+                _ => Style::default(),
+            })
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(Block::default().borders(Borders::ALL).title("VARIABLES"));
+        let mut list_state = ListState::default();
+        if !rows.is_empty() {
+            list_state.select(Some(self.selected));
+        }
+
+        let results = Paragraph::new(
+            self.results
+                .iter()
+                .map(|(name, result)| match result {
+                    Ok(_) => Spans::from(vec![
+                        Span::styled("✔ ", Style::default().fg(Color::Green)),
+                        Span::raw(name.clone()),
+                    ]),
+                    Err(err) => Spans::from(vec![
+                        Span::styled("✘ ", Style::default().fg(Color::Red)),
+                        Span::raw(format!("{}: {}", name, err)),
+                    ]),
+                })
+                .collect::<Vec<Spans>>(),
+        )
+        .block(Block::default().borders(Borders::ALL).title("LAST CAPTURE"));
+
+        let help = Paragraph::new(Spans::from(vec![
+            Span::styled(
+                keymap.label(Action::Delete),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": remove captured  "),
+            Span::styled(
+                keymap.label(Action::ClearCaptured),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": clear captured  "),
+            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(": close"),
+        ]));
+
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(list, list_area, &mut list_state);
+        f.render_widget(results, results_area);
+        f.render_widget(help, help_area);
+
+        Ok(())
+    }
+
+    fn set_state(&mut self, state: ComponentState) {
+        self.state = state;
+    }
+}
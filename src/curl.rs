@@ -0,0 +1,559 @@
+use crate::request_data::{AuthData, HeaderData, RequestData};
+
+pub const FORM_BOUNDARY: &str = "----wica-form-boundary";
+pub const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+
+// Options that only affect curl's own output or transfer behaviour; they are reported and skipped.
+const IGNORED_FLAGS: [&str; 18] = [
+    "-s",
+    "--silent",
+    "-S",
+    "--show-error",
+    "-L",
+    "--location",
+    "-v",
+    "--verbose",
+    "-i",
+    "--include",
+    "-f",
+    "--fail",
+    "-#",
+    "--progress-bar",
+    "--http1.1",
+    "--http2",
+    "-N",
+    "--no-buffer",
+];
+const IGNORED_OPTIONS: [&str; 22] = [
+    "-o",
+    "--output",
+    "-m",
+    "--max-time",
+    "--connect-timeout",
+    "-x",
+    "--proxy",
+    "--cacert",
+    "-E",
+    "--cert",
+    "--key",
+    "-w",
+    "--write-out",
+    "-c",
+    "--cookie-jar",
+    "--resolve",
+    "--retry",
+    "-T",
+    "--upload-file",
+    "-r",
+    "--range",
+    "--limit-rate",
+];
+
+pub struct Import {
+    pub request: RequestData,
+    pub warnings: Vec<String>,
+}
+
+pub fn parse(command: &str) -> Result<Import, String> {
+    let mut tokens = tokenize(command)?;
+    tokens.reverse();
+    match tokens.pop() {
+        Some(program) if program == "curl" => {}
+        _ => return Err("not a curl command".to_string()),
+    }
+
+    let mut request = RequestData::default();
+    let mut method = None;
+    let mut url = None;
+    let mut data = vec![];
+    let mut form = vec![];
+    let mut get = false;
+    let mut head = false;
+    let mut warnings = vec![];
+
+    while let Some(token) = tokens.pop() {
+        if !token.starts_with('-') || token == "-" {
+            if url.is_some() {
+                warnings.push(format!("ignored extra URL {}", token));
+            } else {
+                url = Some(token);
+            }
+            continue;
+        }
+
+        let (option, mut attached) = split_option(&token);
+        if is_flag(&option) && !token.starts_with("--") {
+            // Grouped short flags such as `-sSk`.
+            if let Some(rest) = attached.take() {
+                tokens.push(format!("-{}", rest));
+            }
+        }
+        let mut value = || {
+            attached
+                .clone()
+                .or_else(|| tokens.pop())
+                .ok_or_else(|| format!("{} requires a value", option))
+        };
+        match option.as_str() {
+            "-X" | "--request" => method = Some(value()?),
+            "--url" => url = Some(value()?),
+            "-H" | "--header" => {
+                let header = value()?;
+                match parse_header(&header) {
+                    Some(header) => request.headers.push(header),
+                    None => warnings.push(format!("ignored header removal {}", header)),
+                }
+            }
+            "-A" | "--user-agent" => request.headers.push(header("User-Agent", value()?)),
+            "-e" | "--referer" => request.headers.push(header("Referer", value()?)),
+            "-b" | "--cookie" => {
+                let cookie = value()?;
+                if cookie.contains('=') {
+                    request.headers.push(header("Cookie", cookie));
+                } else {
+                    warnings.push(format!("ignored cookie file {}", cookie));
+                }
+            }
+            "-d" | "--data" | "--data-ascii" | "--data-binary" => {
+                let text = value()?;
+                if let Some(file) = text.strip_prefix('@') {
+                    warnings.push(format!("ignored data file {}", file));
+                } else {
+                    data.push(text);
+                }
+            }
+            "--data-raw" => data.push(value()?),
+            "--data-urlencode" => data.push(urlencode_data(&value()?)),
+            "--json" => {
+                data.push(value()?);
+                request
+                    .headers
+                    .push(header("Content-Type", "application/json".to_string()));
+                request
+                    .headers
+                    .push(header("Accept", "application/json".to_string()));
+            }
+            "-F" | "--form" => {
+                let field = value()?;
+                match field.split_once('=') {
+                    Some((name, content))
+                        if content.starts_with('@') || content.starts_with('<') =>
+                    {
+                        warnings.push(format!("ignored file field {}", name))
+                    }
+                    Some((name, content)) => form.push((name.to_string(), content.to_string())),
+                    None => warnings.push(format!("ignored malformed form field {}", field)),
+                }
+            }
+            "-u" | "--user" => {
+                let user = value()?;
+                let (username, password) = user.split_once(':').unwrap_or((&user, ""));
+                request.auth = AuthData::Basic {
+                    username: username.to_string(),
+                    password: password.to_string(),
+                };
+            }
+            "-G" | "--get" => get = true,
+            "-I" | "--head" => head = true,
+            "-k" | "--insecure" => request.insecure = true,
+            // Response bodies are always read as-is, so there is nothing to turn on.
+            "--compressed" => {}
+            _ if IGNORED_FLAGS.contains(&option.as_str()) => {
+                warnings.push(format!("ignored unsupported option {}", option));
+            }
+            _ if IGNORED_OPTIONS.contains(&option.as_str()) => {
+                let value = value()?;
+                warnings.push(format!("ignored unsupported option {} {}", option, value));
+            }
+            // An unknown option followed by a word takes it as its value when the URL can still
+            // come later, as in `--max-redirs 5 https://example.com`.
+            _ if attached.is_none()
+                && tokens.last().is_some_and(|next| !next.starts_with('-'))
+                && (url.is_some() || url_follows(tokens.iter().rev().skip(1))) =>
+            {
+                let value = tokens.pop().unwrap_or_default();
+                warnings.push(format!("ignored unknown option {} {}", token, value));
+            }
+            _ => warnings.push(format!("ignored unknown option {}", token)),
+        }
+    }
+
+    let mut url = url.ok_or_else(|| "no URL found".to_string())?;
+    if !url.contains("://") {
+        url = format!("http://{}", url);
+    }
+
+    if get && !data.is_empty() {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        url = format!("{}{}{}", url, separator, data.join("&"));
+        data.clear();
+    }
+    if !form.is_empty() {
+        if !data.is_empty() {
+            warnings.push("ignored --data because --form was also given".to_string());
+        }
+        if !request
+            .headers
+            .iter()
+            .any(|h| h.name.eq_ignore_ascii_case("Content-Type"))
+        {
+            request.headers.push(header(
+                "Content-Type",
+                format!("multipart/form-data; boundary={}", FORM_BOUNDARY),
+            ));
+        }
+        request.body = multipart_body(&form);
+    } else if !data.is_empty() {
+        if !request
+            .headers
+            .iter()
+            .any(|h| h.name.eq_ignore_ascii_case("Content-Type"))
+        {
+            request
+                .headers
+                .push(header("Content-Type", FORM_CONTENT_TYPE.to_string()));
+        }
+        request.body = data.join("&");
+    }
+
+    request.method = match method {
+        Some(method) => method,
+        None if head => "HEAD".to_string(),
+        None if !get && !request.body.is_empty() => "POST".to_string(),
+        None => "GET".to_string(),
+    };
+    request.url = url;
+
+    Ok(Import { request, warnings })
+}
+
+fn is_flag(option: &str) -> bool {
+    IGNORED_FLAGS.contains(&option)
+        || matches!(
+            option,
+            "-G" | "--get" | "-I" | "--head" | "-k" | "--insecure" | "--compressed"
+        )
+}
+
+fn takes_value(option: &str) -> bool {
+    IGNORED_OPTIONS.contains(&option)
+        || matches!(
+            option,
+            "-X" | "--request"
+                | "--url"
+                | "-H"
+                | "--header"
+                | "-A"
+                | "--user-agent"
+                | "-e"
+                | "--referer"
+                | "-b"
+                | "--cookie"
+                | "-d"
+                | "--data"
+                | "--data-ascii"
+                | "--data-binary"
+                | "--data-raw"
+                | "--data-urlencode"
+                | "--json"
+                | "-F"
+                | "--form"
+                | "-u"
+                | "--user"
+        )
+}
+
+fn url_follows<'a>(mut tokens: impl Iterator<Item = &'a String>) -> bool {
+    while let Some(token) = tokens.next() {
+        if !token.starts_with('-') || token == "-" {
+            return true;
+        }
+        let (option, attached) = split_option(token);
+        if attached.is_none() && takes_value(&option) {
+            tokens.next();
+        }
+    }
+    false
+}
+
+fn split_option(token: &str) -> (String, Option<String>) {
+    if token.starts_with("--") {
+        match token.split_once('=') {
+            Some((option, value)) => (option.to_string(), Some(value.to_string())),
+            None => (token.to_string(), None),
+        }
+    } else {
+        let mut chars = token.chars();
+        let option = chars.by_ref().take(2).collect::<String>();
+        let rest = chars.collect::<String>();
+        (option, Some(rest).filter(|rest| !rest.is_empty()))
+    }
+}
+
+fn header(name: &str, value: String) -> HeaderData {
+    HeaderData {
+        name: name.to_string(),
+        value,
+        enabled: true,
+    }
+}
+
+fn parse_header(text: &str) -> Option<HeaderData> {
+    match text.split_once(':') {
+        Some((_, value)) if value.trim().is_empty() => None,
+        Some((name, value)) => Some(header(name.trim(), value.trim().to_string())),
+        None => Some(header(text.trim_end_matches(';').trim(), String::new())),
+    }
+}
+
+fn urlencode_data(text: &str) -> String {
+    match text.split_once('=') {
+        Some(("", content)) => urlencode(content),
+        Some((name, content)) => format!("{}={}", name, urlencode(content)),
+        None => urlencode(text),
+    }
+}
+
+pub fn urlencode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'*' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+pub fn multipart_body(fields: &[(String, String)]) -> String {
+    let mut body = String::new();
+    for (name, value) in fields {
+        body.push_str(&format!(
+            "--{}\nContent-Disposition: form-data; name=\"{}\"\n\n{}\n",
+            FORM_BOUNDARY, name, value
+        ));
+    }
+    body.push_str(&format!("--{}--\n", FORM_BOUNDARY));
+    body
+}
+
+// Splits a command line the way a POSIX shell would, including `$'...'` quoting and
+// backslash-newline continuations, which browser "Copy as cURL" output relies on.
+fn tokenize(command: &str) -> Result<Vec<String>, String> {
+    let mut tokens = vec![];
+    let mut token = String::new();
+    let mut in_token = false;
+    let mut chars = command.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut token));
+                    in_token = false;
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some(c) => {
+                    token.push(c);
+                    in_token = true;
+                }
+                None => {}
+            },
+            '\'' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => token.push(c),
+                        None => return Err("unterminated ' quote".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => token.push(c),
+                            Some('\n') => {}
+                            Some(c) => {
+                                token.push('\\');
+                                token.push(c);
+                            }
+                            None => return Err("unterminated \" quote".to_string()),
+                        },
+                        Some(c) => token.push(c),
+                        None => return Err("unterminated \" quote".to_string()),
+                    }
+                }
+            }
+            '$' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some('\\') => token.push(ansi_c_escape(&mut chars)?),
+                        Some(c) => token.push(c),
+                        None => return Err("unterminated $' quote".to_string()),
+                    }
+                }
+            }
+            c => {
+                token.push(c);
+                in_token = true;
+            }
+        }
+    }
+    if in_token {
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn ansi_c_escape(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<char, String> {
+    let hex = |chars: &mut std::iter::Peekable<std::str::Chars>, max: usize| {
+        let mut digits = String::new();
+        while digits.len() < max && chars.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+            digits.extend(chars.next());
+        }
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(std::char::from_u32)
+            .ok_or_else(|| "invalid escape in $' quote".to_string())
+    };
+    match chars.next() {
+        Some('n') => Ok('\n'),
+        Some('t') => Ok('\t'),
+        Some('r') => Ok('\r'),
+        Some('x') => hex(chars, 2),
+        Some('u') => hex(chars, 4),
+        Some('U') => hex(chars, 8),
+        Some(c) => Ok(c),
+        None => Err("unterminated $' quote".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escape(text: &str) -> Result<char, String> {
+        ansi_c_escape(&mut text.chars().peekable())
+    }
+
+    fn header_value<'a>(request: &'a RequestData, name: &str) -> Option<&'a str> {
+        request
+            .headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(name))
+            .map(|h| h.value.as_str())
+    }
+
+    #[test]
+    fn tokenize_quotes_and_continuations() {
+        assert_eq!(
+            tokenize("curl 'a b' \"c \\\"d\\\"\" e\\ f \\\n  g").unwrap(),
+            vec!["curl", "a b", "c \"d\"", "e f", "g"]
+        );
+        assert_eq!(
+            tokenize("curl $'line\\nnext\\x41\\u00e9\\''").unwrap(),
+            vec!["curl", "line\nnextAé'"]
+        );
+        assert!(tokenize("curl 'open").is_err());
+        assert!(tokenize("curl $'open").is_err());
+    }
+
+    #[test]
+    fn ansi_c_escapes() {
+        assert_eq!(escape("n"), Ok('\n'));
+        assert_eq!(escape("t"), Ok('\t'));
+        assert_eq!(escape("x41"), Ok('A'));
+        assert_eq!(escape("u00e9"), Ok('é'));
+        assert_eq!(escape("U0001F600"), Ok('😀'));
+        assert_eq!(escape("'"), Ok('\''));
+        assert!(escape("xzz").is_err());
+        assert!(escape("").is_err());
+    }
+
+    #[test]
+    fn parse_grouped_short_flags() {
+        let import = parse("curl -sSkX PUT https://example.com").unwrap();
+        assert!(import.request.insecure);
+        assert_eq!(import.request.method, "PUT");
+        assert_eq!(import.request.url, "https://example.com");
+    }
+
+    #[test]
+    fn parse_data_defaults_to_form_post() {
+        let import = parse("curl example.com -d a=1 --data b=2").unwrap();
+        assert_eq!(import.request.method, "POST");
+        assert_eq!(import.request.url, "http://example.com");
+        assert_eq!(import.request.body, "a=1&b=2");
+        assert_eq!(
+            header_value(&import.request, "Content-Type"),
+            Some(FORM_CONTENT_TYPE)
+        );
+    }
+
+    #[test]
+    fn parse_get_moves_data_to_query() {
+        let import = parse("curl -G 'https://example.com/?x=1' -d a=1 -d b=2").unwrap();
+        assert_eq!(import.request.method, "GET");
+        assert_eq!(import.request.url, "https://example.com/?x=1&a=1&b=2");
+        assert!(import.request.body.is_empty());
+    }
+
+    #[test]
+    fn parse_form_fields() {
+        let import = parse("curl https://example.com -F name=value -F file=@a.txt").unwrap();
+        assert_eq!(import.request.method, "POST");
+        assert_eq!(
+            import.request.body,
+            multipart_body(&[("name".to_string(), "value".to_string())])
+        );
+        assert_eq!(
+            header_value(&import.request, "Content-Type"),
+            Some(format!("multipart/form-data; boundary={}", FORM_BOUNDARY).as_str())
+        );
+        assert_eq!(import.warnings, vec!["ignored file field file"]);
+    }
+
+    #[test]
+    fn parse_unknown_options_with_values() {
+        let import = parse("curl --max-redirs 5 https://example.com").unwrap();
+        assert_eq!(import.request.url, "https://example.com");
+        assert_eq!(
+            import.warnings,
+            vec!["ignored unknown option --max-redirs 5"]
+        );
+
+        let import = parse("curl https://example.com --max-redirs 5").unwrap();
+        assert_eq!(import.request.url, "https://example.com");
+        assert_eq!(
+            import.warnings,
+            vec!["ignored unknown option --max-redirs 5"]
+        );
+
+        let import = parse("curl --frobnicate https://example.com").unwrap();
+        assert_eq!(import.request.url, "https://example.com");
+        assert_eq!(import.warnings, vec!["ignored unknown option --frobnicate"]);
+
+        let import = parse("curl -o out.json https://example.com").unwrap();
+        assert_eq!(import.request.url, "https://example.com");
+        assert_eq!(
+            import.warnings,
+            vec!["ignored unsupported option -o out.json"]
+        );
+    }
+
+    #[test]
+    fn parse_rejects_other_commands() {
+        assert!(parse("wget https://example.com").is_err());
+        assert!(parse("curl -s").is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::workspace;
+
+const ENVIRONMENTS_FILE: &str = "environments.toml";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct EnvironmentsFile {
+    active: Option<String>,
+    #[serde(default)]
+    environments: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+pub struct EnvironmentStore {
+    path: PathBuf,
+    data: EnvironmentsFile,
+}
+
+impl EnvironmentStore {
+    pub fn load() -> Result<Self> {
+        let path = workspace::dir().join(ENVIRONMENTS_FILE);
+        let data = if path.exists() {
+            toml::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            EnvironmentsFile::default()
+        };
+        Ok(Self { path, data })
+    }
+
+    pub fn active(&self) -> Option<&str> {
+        self.data
+            .active
+            .as_deref()
+            .filter(|name| self.data.environments.contains_key(*name))
+    }
+
+    pub fn set_active(&mut self, name: Option<&str>) -> Result<()> {
+        self.data.active = name.map(|name| name.to_string());
+        self.save()
+    }
+
+    pub fn names(&self) -> Vec<&String> {
+        self.data.environments.keys().collect()
+    }
+
+    pub fn variables(&self) -> HashMap<String, String> {
+        self.active()
+            .and_then(|name| self.variables_of(name))
+            .unwrap_or_default()
+    }
+
+    pub fn variables_of(&self, name: &str) -> Option<HashMap<String, String>> {
+        self.data
+            .environments
+            .get(name)
+            .map(|vars| vars.clone().into_iter().collect())
+    }
+
+    // Variables already in the environment that are not given are kept.
+    pub fn set_variables(&mut self, name: &str, vars: BTreeMap<String, String>) -> Result<()> {
+        self.data
+            .environments
+            .entry(name.to_string())
+            .or_default()
+            .extend(vars);
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(
+            &self.path,
+            toml::to_string_pretty(&toml::Value::try_from(&self.data)?)?,
+        )?;
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 use std::io;
+use std::path::PathBuf;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 use termion::event::Key;
 use termion::input::TermRead;
 
+use crate::collection::SavedRequest;
 use crate::components::ComponentPosition;
 use crate::http_request;
+use crate::request_data::RequestData;
 
 pub enum Event<I> {
     KeyInput(I),
@@ -14,8 +17,30 @@ pub enum Event<I> {
     Quit,
     SetQuery(Vec<char>),
     Request,
-    Response(http_request::Response), // TODO: Option<http_request::Response>に変更する
+    ResendRequest(RequestData),
+    LoadRequest(RequestData, RequestOrigin),
+    SaveRequest(Option<PathBuf>),
+    SavedRequestRenamed(PathBuf, PathBuf),
+    SavedRequestDeleted(PathBuf),
+    WriteHttpFile,
+    SwitchEnvironment,
+    NewTab,
+    CloseTab,
+    DuplicateTab,
+    SwitchTab(isize),
+    Response(usize, usize, http_request::Response),
+    RequestError(usize, usize, http_request::Error),
     ChangeFocus(ComponentPosition),
+    Copy(String),
+    Notify(Result<String, String>),
+}
+
+// Where a request put into the editor came from. Saving and writing back with `w` and the
+// captures and assertions applied on send follow it only while it is still in the editor.
+pub enum RequestOrigin {
+    Other,
+    Collection(PathBuf, Box<SavedRequest>),
+    HttpFile(PathBuf, usize),
 }
 
 pub struct Events {
@@ -51,12 +76,10 @@ impl Events {
             let tx = tx.clone();
             thread::spawn(move || {
                 let stdin = io::stdin();
-                for evt in stdin.keys() {
-                    if let Ok(key) = evt {
-                        if let Err(err) = tx.send(Event::KeyInput(key)) {
-                            eprintln!("{}", err);
-                            return;
-                        }
+                for key in stdin.keys().flatten() {
+                    if let Err(err) = tx.send(Event::KeyInput(key)) {
+                        eprintln!("{}", err);
+                        return;
                     }
                 }
             })
@@ -83,9 +106,13 @@ impl Events {
         self.rx.recv()
     }
 
-    pub fn sender(&self) -> EventSender {
+    pub fn sender(&self) -> EventSender<'_> {
         EventSender::new(&self.tx)
     }
+
+    pub fn owned_sender(&self) -> mpsc::Sender<Event<Key>> {
+        self.tx.clone()
+    }
 }
 
 pub struct EventSender<'a> {
@@ -93,14 +120,13 @@ pub struct EventSender<'a> {
 }
 
 impl<'a> EventSender<'a> {
-    pub fn new(tx: &mpsc::Sender<Event<Key>>) -> EventSender {
+    pub fn new(tx: &mpsc::Sender<Event<Key>>) -> EventSender<'_> {
         EventSender { tx }
     }
 
     pub fn send(&self, event: Event<Key>) {
         if let Err(err) = self.tx.send(event) {
             eprintln!("{}", err);
-            return;
         }
     }
 }
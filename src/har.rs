@@ -0,0 +1,375 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::collection::{CollectionStore, SavedRequest};
+use crate::history::{HistoryEntry, HistoryStore};
+use crate::request_data::{HeaderData, RequestData};
+
+const HAR_VERSION: &str = "1.2";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Har {
+    log: Log,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Log {
+    version: String,
+    creator: Creator,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Creator {
+    name: String,
+    version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    started_date_time: String,
+    time: f64,
+    request: Request,
+    response: Response,
+    #[serde(default)]
+    cache: serde_json::Value,
+    #[serde(default)]
+    timings: Timings,
+    #[serde(rename = "_error", default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+    method: String,
+    url: String,
+    #[serde(default)]
+    http_version: String,
+    #[serde(default)]
+    cookies: Vec<NameValue>,
+    #[serde(default)]
+    headers: Vec<NameValue>,
+    #[serde(default)]
+    query_string: Vec<NameValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    post_data: Option<PostData>,
+    #[serde(default = "unknown_size")]
+    headers_size: i64,
+    #[serde(default = "unknown_size")]
+    body_size: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Response {
+    status: u16,
+    #[serde(default)]
+    status_text: String,
+    #[serde(default)]
+    http_version: String,
+    #[serde(default)]
+    cookies: Vec<NameValue>,
+    #[serde(default)]
+    headers: Vec<NameValue>,
+    #[serde(default)]
+    content: Content,
+    #[serde(rename = "redirectURL", default)]
+    redirect_url: String,
+    #[serde(default = "unknown_size")]
+    headers_size: i64,
+    #[serde(default = "unknown_size")]
+    body_size: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NameValue {
+    name: String,
+    #[serde(default)]
+    value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostData {
+    #[serde(default)]
+    mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    params: Vec<NameValue>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Content {
+    #[serde(default)]
+    size: i64,
+    #[serde(default)]
+    mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Timings {
+    send: f64,
+    wait: f64,
+    receive: f64,
+}
+
+fn unknown_size() -> i64 {
+    -1
+}
+
+fn name_values(pairs: impl Iterator<Item = (String, String)>) -> Vec<NameValue> {
+    pairs
+        .map(|(name, value)| NameValue { name, value })
+        .collect()
+}
+
+impl Entry {
+    fn from_history(entry: &HistoryEntry) -> Self {
+        let request = &entry.request;
+        let content_type = request
+            .enabled_headers()
+            .find(|h| h.name.eq_ignore_ascii_case("content-type"))
+            .map(|h| h.value.clone())
+            .unwrap_or_default();
+        let time = entry.delay_ms.unwrap_or_default() as f64;
+        let body = entry.response.clone().unwrap_or_default();
+        let body_size = entry
+            .response_size
+            .map_or(body.len() as i64, |size| size as i64);
+
+        Self {
+            started_date_time: entry.sent_at.to_rfc3339(),
+            time,
+            request: Request {
+                method: request.method.clone(),
+                url: request.url.clone(),
+                http_version: "HTTP/1.1".to_string(),
+                cookies: vec![],
+                headers: name_values(
+                    request
+                        .enabled_headers()
+                        .map(|h| (h.name.clone(), h.value.clone())),
+                ),
+                query_string: name_values(
+                    request
+                        .query()
+                        .split('&')
+                        .filter(|pair| !pair.is_empty())
+                        .map(|pair| {
+                            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                            (name.to_string(), value.to_string())
+                        }),
+                ),
+                post_data: Some(PostData {
+                    mime_type: content_type,
+                    text: Some(request.body.clone()),
+                    params: vec![],
+                })
+                .filter(|_| !request.body.is_empty()),
+                headers_size: -1,
+                body_size: request.body.len() as i64,
+            },
+            response: Response {
+                status: entry.status.unwrap_or_default(),
+                status_text: entry
+                    .status
+                    .and_then(|status| reqwest::StatusCode::from_u16(status).ok())
+                    .and_then(|status| status.canonical_reason())
+                    .unwrap_or_default()
+                    .to_string(),
+                http_version: "HTTP/1.1".to_string(),
+                cookies: vec![],
+                headers: name_values(entry.response_headers.iter().cloned()),
+                content: Content {
+                    size: body_size,
+                    mime_type: entry
+                        .response_headers
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or_default(),
+                    text: entry.response.clone(),
+                    encoding: None,
+                    comment: Some(format!(
+                        "text truncated to the first {} bytes kept in the history",
+                        body.len()
+                    ))
+                    .filter(|_| entry.response_truncated),
+                },
+                redirect_url: String::new(),
+                headers_size: -1,
+                body_size,
+            },
+            cache: serde_json::json!({}),
+            timings: Timings {
+                send: 0.0,
+                wait: time,
+                receive: 0.0,
+            },
+            error: entry.error.clone(),
+        }
+    }
+
+    fn request_data(&self) -> RequestData {
+        let body = match &self.request.post_data {
+            Some(PostData {
+                text: Some(text), ..
+            }) => text.clone(),
+            Some(PostData { params, .. }) => params
+                .iter()
+                .map(|p| format!("{}={}", p.name, p.value))
+                .collect::<Vec<String>>()
+                .join("&"),
+            None => String::new(),
+        };
+        RequestData {
+            method: self.request.method.clone(),
+            url: self.request.url.clone(),
+            // HTTP/2 pseudo headers such as `:authority` cannot be sent as regular headers.
+            headers: self
+                .request
+                .headers
+                .iter()
+                .filter(|h| !h.name.starts_with(':'))
+                .map(|h| HeaderData {
+                    name: h.name.clone(),
+                    value: h.value.clone(),
+                    enabled: true,
+                })
+                .collect(),
+            body,
+            ..RequestData::default()
+        }
+    }
+
+    fn to_history(&self) -> HistoryEntry {
+        let body = self.response.content.text.as_ref().map(|text| {
+            match self.response.content.encoding.as_deref() {
+                Some("base64") => base64::decode(text)
+                    .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+                    .unwrap_or_default(),
+                _ => text.clone(),
+            }
+        });
+        let status = Some(self.response.status).filter(|status| *status > 0);
+        HistoryEntry {
+            sent_at: DateTime::parse_from_rfc3339(&self.started_date_time)
+                .map(|date| date.with_timezone(&Local))
+                .unwrap_or_else(|_| Local::now()),
+            request: self.request_data(),
+            status,
+            delay_ms: Some(self.time.max(0.0) as u64),
+            response_headers: self
+                .response
+                .headers
+                .iter()
+                .map(|h| (h.name.clone(), h.value.clone()))
+                .collect(),
+            response_size: Some(self.response.content.size)
+                .filter(|size| *size >= 0)
+                .map(|size| size as u64),
+            response_truncated: false,
+            response: body,
+            error: self
+                .error
+                .clone()
+                .or_else(|| Some("no response recorded".to_string()).filter(|_| status.is_none())),
+        }
+    }
+
+    fn file_name(&self, index: usize) -> String {
+        let last_segment = self
+            .request
+            .url
+            .split(['?', '#'])
+            .next()
+            .and_then(|path| path.trim_end_matches('/').rsplit('/').next())
+            .unwrap_or_default();
+        let name = last_segment
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || "-_.".contains(c) {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        format!("{:03} {} {}", index + 1, self.request.method, name)
+            .trim()
+            .to_string()
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<Entry>> {
+    let har: Har = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(har.log.entries)
+}
+
+pub fn import_to_history(path: &Path) -> Result<usize> {
+    let entries = read(path)?;
+    let mut store = HistoryStore::load();
+    for entry in &entries {
+        store.append(entry.to_history())?;
+    }
+    Ok(entries.len())
+}
+
+#[derive(Default)]
+pub struct Report {
+    pub requests: usize,
+    pub renamed: Vec<String>,
+}
+
+pub fn import_to_collection(path: &Path, folder: &Path) -> Result<Report> {
+    let entries = read(path)?;
+    let store = CollectionStore::default();
+    let mut used = HashSet::new();
+    let mut report = Report::default();
+    for (i, entry) in entries.iter().enumerate() {
+        let saved = SavedRequest {
+            request: entry.request_data(),
+            ..SavedRequest::default()
+        };
+        let (path, collided) = store.unique_path(folder, &entry.file_name(i), &mut used);
+        if collided {
+            report.renamed.push(path.display().to_string());
+        }
+        store.save(&path, &saved)?;
+        report.requests += 1;
+    }
+    Ok(report)
+}
+
+pub fn export(path: &Path, entries: &[&HistoryEntry]) -> Result<()> {
+    let har = Har {
+        log: Log {
+            version: HAR_VERSION.to_string(),
+            creator: Creator {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            entries: entries
+                .iter()
+                .map(|entry| Entry::from_history(&entry.redacted()))
+                .collect(),
+        },
+    };
+    fs::write(path, serde_json::to_string_pretty(&har)?)?;
+    Ok(())
+}
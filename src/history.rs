@@ -0,0 +1,173 @@
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::http_request;
+use crate::request_data::{AuthData, RequestData};
+
+const HISTORY_FILE: &str = "history.jsonl";
+const MAX_RESPONSE_LEN: usize = 4096;
+const REDACTED: &str = "<redacted>";
+const SECRET_HEADERS: [&str; 3] = ["authorization", "proxy-authorization", "cookie"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub sent_at: DateTime<Local>,
+    pub request: RequestData,
+    pub status: Option<u16>,
+    pub delay_ms: Option<u64>,
+    #[serde(default)]
+    pub response_headers: Vec<(String, String)>,
+    pub response: Option<String>,
+    // Size of the whole response body; `response` keeps at most MAX_RESPONSE_LEN bytes of it.
+    #[serde(default)]
+    pub response_size: Option<u64>,
+    #[serde(default)]
+    pub response_truncated: bool,
+    pub error: Option<String>,
+}
+
+impl HistoryEntry {
+    pub fn from_response(
+        sent_at: DateTime<Local>,
+        request: RequestData,
+        resp: &http_request::Response,
+    ) -> Self {
+        let mut response = String::from_utf8_lossy(&resp.raw_body).to_string();
+        let response_truncated = response.len() > MAX_RESPONSE_LEN;
+        if response_truncated {
+            let mut end = MAX_RESPONSE_LEN;
+            while !response.is_char_boundary(end) {
+                end -= 1;
+            }
+            response.truncate(end);
+        }
+
+        Self {
+            sent_at,
+            request,
+            status: Some(resp.status.as_u16()),
+            delay_ms: Some(resp.delay.as_millis() as u64),
+            response_headers: resp.header.clone(),
+            response: Some(response),
+            response_size: Some(resp.raw_body.len() as u64),
+            response_truncated,
+            error: None,
+        }
+    }
+
+    pub fn from_error(
+        sent_at: DateTime<Local>,
+        request: RequestData,
+        err: &http_request::Error,
+    ) -> Self {
+        Self {
+            sent_at,
+            request,
+            status: None,
+            delay_ms: None,
+            response_headers: vec![],
+            response: None,
+            response_size: None,
+            response_truncated: false,
+            error: Some(err.to_string()),
+        }
+    }
+
+    pub fn has_redacted_secrets(&self) -> bool {
+        let auth = match &self.request.auth {
+            AuthData::None => None,
+            AuthData::Basic { password, .. } => Some(password),
+            AuthData::Bearer { token } => Some(token),
+            AuthData::ApiKeyHeader { value, .. } | AuthData::ApiKeyQuery { value, .. } => {
+                Some(value)
+            }
+        };
+        auth.into_iter()
+            .chain(
+                self.request
+                    .headers
+                    .iter()
+                    .filter(|h| SECRET_HEADERS.contains(&h.name.to_lowercase().as_str()))
+                    .map(|h| &h.value),
+            )
+            .any(|value| value == REDACTED)
+    }
+
+    pub fn redacted(&self) -> Self {
+        let mut entry = self.clone();
+        entry.redact_secrets();
+        entry
+    }
+
+    fn redact_secrets(&mut self) {
+        match &mut self.request.auth {
+            AuthData::None => {}
+            AuthData::Basic { password, .. } => redact(password),
+            AuthData::Bearer { token } => redact(token),
+            AuthData::ApiKeyHeader { value, .. } | AuthData::ApiKeyQuery { value, .. } => {
+                redact(value)
+            }
+        }
+        for header in &mut self.request.headers {
+            if SECRET_HEADERS.contains(&header.name.to_lowercase().as_str()) {
+                redact(&mut header.value);
+            }
+        }
+        for (name, value) in &mut self.response_headers {
+            if name.eq_ignore_ascii_case("set-cookie") {
+                redact(value);
+            }
+        }
+    }
+}
+
+// Values that only refer to variables are kept, since the secret itself stays in the environment.
+fn redact(value: &mut String) {
+    if !value.is_empty() && !value.contains("{{") {
+        *value = REDACTED.to_string();
+    }
+}
+
+pub struct HistoryStore {
+    path: Option<PathBuf>,
+    entries: Vec<HistoryEntry>,
+}
+
+impl HistoryStore {
+    pub fn load() -> Self {
+        let path = dirs::data_dir().map(|dir| dir.join("wica").join(HISTORY_FILE));
+        let entries = path
+            .as_ref()
+            .and_then(|path| fs::File::open(path).ok())
+            .map(|file| {
+                BufReader::new(file)
+                    .lines()
+                    .filter_map(|line| serde_json::from_str(&line.ok()?).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { path, entries }
+    }
+
+    pub fn entries(&self) -> &Vec<HistoryEntry> {
+        &self.entries
+    }
+
+    // Credentials are never written to the history file, nor passed on to HAR exports.
+    pub fn append(&mut self, entry: HistoryEntry) -> Result<()> {
+        if let Some(path) = &self.path {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{}", serde_json::to_string(&entry.redacted())?)?;
+        }
+        self.entries.push(entry);
+        Ok(())
+    }
+}
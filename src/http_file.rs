@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+
+use crate::request_data::{AuthData, HeaderData, RequestData};
+use crate::template;
+
+const SEPARATOR: &str = "###";
+const METHODS: [&str; 9] = [
+    "GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS", "TRACE", "CONNECT",
+];
+
+pub struct HttpRequest {
+    pub name: String,
+    pub request: RequestData,
+    // Lines from the request line to the end of the body; comments, names and variables
+    // before it are left untouched when the request is written back.
+    start: usize,
+    end: usize,
+}
+
+pub struct HttpFile {
+    pub path: PathBuf,
+    pub requests: Vec<HttpRequest>,
+    variables: Vec<(String, String)>,
+    lines: Vec<String>,
+    trailing_newline: bool,
+}
+
+enum Section {
+    Preamble,
+    Headers,
+    Body,
+}
+
+fn is_comment(line: &str) -> bool {
+    line.starts_with('#') || line.starts_with("//")
+}
+
+fn parse_request_line(line: &str) -> (String, String) {
+    let mut words = line.split_whitespace().collect::<Vec<&str>>();
+    if words.len() > 1 && words.last().is_some_and(|w| w.starts_with("HTTP/")) {
+        words.pop();
+    }
+    match words.split_first() {
+        Some((method, rest)) if METHODS.contains(method) && !rest.is_empty() => {
+            (method.to_string(), rest.join(" "))
+        }
+        _ => ("GET".to_string(), words.join(" ")),
+    }
+}
+
+// `Authorization: Basic user:password` and `Basic user password` are shorthands that the
+// client encodes itself; an already encoded value is kept as a plain header.
+fn basic_auth(name: &str, value: &str) -> Option<AuthData> {
+    if !name.eq_ignore_ascii_case("Authorization") {
+        return None;
+    }
+    let credentials = value.strip_prefix("Basic ")?.trim();
+    let (username, password) = credentials
+        .split_once(':')
+        .or_else(|| credentials.split_once(' '))?;
+    Some(AuthData::Basic {
+        username: username.trim().to_string(),
+        password: password.trim().to_string(),
+    })
+}
+
+// Disabled headers are written back as `# Name: value`; comments that do not look like a
+// header, such as a commented out URL, stay plain comments.
+fn disabled_header(comment: &str) -> Option<HeaderData> {
+    let (name, value) = comment.trim_start_matches(['#', '/']).split_once(':')?;
+    let (name, value) = (name.trim(), value.trim());
+    let is_token = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c));
+    if !is_token || value.starts_with("//") {
+        return None;
+    }
+    Some(HeaderData {
+        name: name.to_string(),
+        value: value.to_string(),
+        enabled: false,
+    })
+}
+
+impl HttpFile {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| anyhow!("cannot read {}: {}", path.display(), err))?;
+        Ok(Self::parse(path, &text))
+    }
+
+    fn parse(path: &Path, text: &str) -> Self {
+        let lines = text
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        let mut file = Self {
+            path: path.to_path_buf(),
+            requests: vec![],
+            variables: vec![],
+            lines,
+            trailing_newline: text.ends_with('\n'),
+        };
+
+        let mut block_start = 0;
+        for i in 0..=file.lines.len() {
+            let at_separator = file
+                .lines
+                .get(i)
+                .is_none_or(|line| line.trim_start().starts_with(SEPARATOR));
+            if at_separator {
+                file.parse_block(block_start, i);
+                block_start = i;
+            }
+        }
+        file
+    }
+
+    fn parse_block(&mut self, start: usize, end: usize) {
+        let mut name = self.lines.get(start).and_then(|line| {
+            line.trim()
+                .strip_prefix(SEPARATOR)
+                .map(|title| title.trim_start_matches('#').trim().to_string())
+                .filter(|title| !title.is_empty())
+        });
+        let mut section = Section::Preamble;
+        let mut request = RequestData::default();
+        let mut request_start = None;
+        let mut body = vec![];
+        let mut last_line = start;
+
+        for i in start..end {
+            let line = self.lines[i].trim_end();
+            let trimmed = line.trim_start();
+            match section {
+                Section::Preamble => {
+                    if trimmed.is_empty() || trimmed.starts_with(SEPARATOR) {
+                        continue;
+                    }
+                    if is_comment(trimmed) {
+                        let comment = trimmed.trim_start_matches(['#', '/']).trim();
+                        if let Some(rest) = comment.strip_prefix("@name") {
+                            name = Some(rest.trim_start_matches([' ', '=']).trim().to_string());
+                        }
+                        continue;
+                    }
+                    if let Some((var, value)) = trimmed
+                        .strip_prefix('@')
+                        .and_then(|definition| definition.split_once('='))
+                    {
+                        self.variables
+                            .push((var.trim().to_string(), value.trim().to_string()));
+                        continue;
+                    }
+                    let (method, url) = parse_request_line(trimmed);
+                    request.method = method;
+                    request.url = url;
+                    request_start = Some(i);
+                    last_line = i;
+                    section = Section::Headers;
+                }
+                Section::Headers => {
+                    if trimmed.is_empty() {
+                        section = Section::Body;
+                    } else if trimmed.starts_with('?') || trimmed.starts_with('&') {
+                        request.url.push_str(trimmed);
+                        last_line = i;
+                    } else if is_comment(trimmed) {
+                        request.headers.extend(disabled_header(trimmed));
+                        last_line = i;
+                    } else if let Some((name, value)) = trimmed.split_once(':') {
+                        let (name, value) = (name.trim(), value.trim());
+                        match basic_auth(name, value) {
+                            Some(auth) => request.auth = auth,
+                            None => request.headers.push(HeaderData {
+                                name: name.to_string(),
+                                value: value.to_string(),
+                                enabled: true,
+                            }),
+                        }
+                        last_line = i;
+                    }
+                }
+                Section::Body => body.push((i, line)),
+            }
+        }
+
+        let request_start = match request_start {
+            Some(request_start) => request_start,
+            None => return,
+        };
+        // Comments between the body and the next separator usually describe the next request.
+        while body.last().is_some_and(|(_, line)| {
+            let line = line.trim();
+            line.is_empty() || is_comment(line)
+        }) {
+            body.pop();
+        }
+        if let Some((i, _)) = body.last() {
+            last_line = *i;
+        }
+        request.body = body
+            .iter()
+            .map(|(_, line)| *line)
+            .collect::<Vec<&str>>()
+            .join("\n");
+        self.requests.push(HttpRequest {
+            name: name.unwrap_or_else(|| format!("{} {}", request.method, request.url)),
+            request,
+            start: request_start,
+            end: last_line + 1,
+        });
+    }
+
+    // File variables may refer to environment variables and to each other.
+    pub fn variables(&self, base: &HashMap<String, String>) -> HashMap<String, String> {
+        let mut variables = base.clone();
+        for (name, value) in &self.variables {
+            let value = template::render(value, &variables);
+            variables.insert(name.clone(), value);
+        }
+        variables
+    }
+
+    pub fn update(&mut self, index: usize, data: &RequestData) -> Result<()> {
+        let (start, end) = match self.requests.get(index) {
+            Some(request) => (request.start, request.end),
+            None => {
+                return Err(anyhow!(
+                    "no request {} in {}",
+                    index + 1,
+                    self.path.display()
+                ))
+            }
+        };
+        // Edits made elsewhere in the file since it was opened are kept, but the request itself
+        // must still be the one that was loaded.
+        let current = Self::load(&self.path)?;
+        if current.lines.get(start..end) != self.lines.get(start..end) {
+            return Err(anyhow!(
+                "{} changed on disk, reload it before writing",
+                self.path.display()
+            ));
+        }
+        let mut lines = current.lines[..start].to_vec();
+        lines.extend(format_request(data));
+        lines.extend_from_slice(&current.lines[end..]);
+
+        let mut text = lines.join("\n");
+        if current.trailing_newline {
+            text.push('\n');
+        }
+        fs::write(&self.path, &text)?;
+        *self = Self::parse(&self.path, &text);
+        Ok(())
+    }
+}
+
+fn format_request(data: &RequestData) -> Vec<String> {
+    let mut url = data.url.clone();
+    let mut headers = vec![];
+    match &data.auth {
+        AuthData::None => {}
+        AuthData::Basic { username, password } => {
+            headers.push(format!("Authorization: Basic {}:{}", username, password));
+        }
+        AuthData::Bearer { token } => headers.push(format!("Authorization: Bearer {}", token)),
+        AuthData::ApiKeyHeader { key, value } => headers.push(format!("{}: {}", key, value)),
+        AuthData::ApiKeyQuery { key, value } => {
+            let separator = if url.contains('?') { '&' } else { '?' };
+            url = format!("{}{}{}={}", url, separator, key, value);
+        }
+    }
+    let mut lines = vec![format!("{} {}", data.method, url)];
+    // Disabled headers are kept as comments so they are not lost from the file.
+    lines.extend(data.headers.iter().map(|h| {
+        let prefix = if h.enabled { "" } else { "# " };
+        format!("{}{}: {}", prefix, h.name, h.value)
+    }));
+    lines.extend(headers);
+    if !data.body.is_empty() {
+        lines.push(String::new());
+        lines.extend(data.body.lines().map(|line| line.to_string()));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "@host = https://example.com
+@users = {{host}}/users
+
+### List users
+GET {{users}}
+    ?page=1
+Accept: application/json
+# X-Debug: 1
+
+### Create user
+# @name create
+POST {{users}} HTTP/1.1
+Content-Type: application/json
+Authorization: Basic alice:secret
+
+{\"name\": \"bob\"}
+
+###
+# The last request
+DELETE {{users}}/1
+";
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("wica-{}-{}.http", name, std::process::id()))
+    }
+
+    #[test]
+    fn parse_requests() {
+        let file = HttpFile::parse(Path::new("api.http"), TEXT);
+        let names = file
+            .requests
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["List users", "create", "DELETE {{users}}/1"]);
+
+        let list = &file.requests[0].request;
+        assert_eq!(list.method, "GET");
+        assert_eq!(list.url, "{{users}}?page=1");
+        assert_eq!(list.headers.len(), 2);
+        assert!(list.headers[0].enabled);
+        assert_eq!(list.headers[1].name, "X-Debug");
+        assert!(!list.headers[1].enabled);
+
+        let create = &file.requests[1].request;
+        assert_eq!(create.method, "POST");
+        assert_eq!(create.url, "{{users}}");
+        assert_eq!(create.body, "{\"name\": \"bob\"}");
+        assert!(matches!(
+            &create.auth,
+            AuthData::Basic { username, password } if username == "alice" && password == "secret"
+        ));
+
+        let variables = file.variables(&HashMap::new());
+        assert_eq!(variables["users"], "https://example.com/users");
+    }
+
+    #[test]
+    fn parse_without_separators() {
+        let file = HttpFile::parse(Path::new("one.http"), "# comment\nhttps://example.com\n");
+        assert_eq!(file.requests.len(), 1);
+        assert_eq!(file.requests[0].request.method, "GET");
+        assert_eq!(file.requests[0].request.url, "https://example.com");
+    }
+
+    #[test]
+    fn format_request_round_trips() {
+        let file = HttpFile::parse(Path::new("api.http"), TEXT);
+        for request in &file.requests {
+            let text = format_request(&request.request).join("\n");
+            let reparsed = HttpFile::parse(Path::new("api.http"), &text);
+            let data = &reparsed.requests[0].request;
+            assert_eq!(data.method, request.request.method);
+            assert_eq!(data.url, request.request.url);
+            assert_eq!(data.headers, request.request.headers);
+            assert_eq!(data.auth, request.request.auth);
+            assert_eq!(data.body, request.request.body);
+        }
+    }
+
+    #[test]
+    fn update_rewrites_only_the_request() {
+        let path = temp_path("update");
+        fs::write(&path, TEXT).unwrap();
+        let mut file = HttpFile::load(&path).unwrap();
+        let mut data = file.requests[0].request.clone();
+        data.url = "{{host}}/people".to_string();
+        file.update(0, &data).unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(text.contains("### List users\nGET {{host}}/people\n"));
+        assert!(text.ends_with("# The last request\nDELETE {{users}}/1\n"));
+        assert_eq!(file.requests[0].request.url, "{{host}}/people");
+    }
+
+    #[test]
+    fn update_refuses_when_the_request_changed_on_disk() {
+        let path = temp_path("stale");
+        fs::write(&path, TEXT).unwrap();
+        let mut file = HttpFile::load(&path).unwrap();
+        fs::write(&path, TEXT.replace("?page=1", "?page=2")).unwrap();
+        let data = file.requests[0].request.clone();
+        let result = file.update(0, &data);
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+        assert!(text.contains("?page=2"));
+    }
+}
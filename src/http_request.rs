@@ -1,45 +1,292 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
-use reqwest::{Method, StatusCode};
+use reqwest::{
+    header::{HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    Method, StatusCode, Url,
+};
+
+use crate::renderer::{self, BodyKind};
+use crate::request_data::{AuthData, RequestData};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
 
 pub struct Response {
+    pub url: String,
     pub status: StatusCode,
     pub header: Vec<(String, String)>,
     pub body: Vec<String>,
+    pub raw_body: Vec<u8>,
+    pub body_kind: BodyKind,
     pub delay: Duration,
 }
 
-pub async fn request(
-    req: &reqwest::Request,
-    body: String,
-) -> Result<Option<Response>, Box<dyn std::error::Error>> {
+impl Response {
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        self.header
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn file_name(&self) -> String {
+        let base_name = |name: String| {
+            name.rsplit(['/', '\\'])
+                .next()
+                .map(|name| name.trim().to_string())
+                .filter(|name| !matches!(name.as_str(), "" | "." | ".."))
+        };
+        let from_url = || {
+            Url::parse(&self.url)
+                .ok()?
+                .path_segments()?
+                .next_back()
+                .map(percent_decode)
+                .and_then(base_name)
+        };
+        self.header_value("content-disposition")
+            .and_then(content_disposition_file_name)
+            .and_then(base_name)
+            .or_else(from_url)
+            .unwrap_or_else(|| format!("response.{}", self.body_kind.extension()))
+    }
+
+    pub fn json_body(&self) -> Result<serde_json::Value, String> {
+        serde_json::from_slice(&self.raw_body).map_err(|e| format!("body is not JSON: {}", e))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorKind {
+    Url,
+    Header,
+    Connect,
+    Timeout,
+    Tls,
+    Decode,
+    Request,
+    Cancelled,
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ErrorKind::Url => "URL PARSE ERROR",
+            ErrorKind::Header => "HEADER ERROR",
+            ErrorKind::Connect => "CONNECTION ERROR",
+            ErrorKind::Timeout => "TIMEOUT",
+            ErrorKind::Tls => "TLS ERROR",
+            ErrorKind::Decode => "DECODE ERROR",
+            ErrorKind::Request => "REQUEST ERROR",
+            ErrorKind::Cancelled => "REQUEST CANCELLED",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new<E: fmt::Display>(kind: ErrorKind, err: E) -> Self {
+        Self {
+            kind,
+            message: err.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind.as_str(), self.message)
+    }
+}
+
+impl StdError for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        let mut message = err.to_string();
+        let mut is_tls = false;
+        let mut source = err.source();
+        while let Some(cause) = source {
+            let cause_message = cause.to_string();
+            let lower = cause_message.to_lowercase();
+            if lower.contains("certificate") || lower.contains("tls") || lower.contains("ssl") {
+                is_tls = true;
+            }
+            message = format!("{}: {}", message, cause_message);
+            source = cause.source();
+        }
+
+        let kind = if err.is_timeout() {
+            ErrorKind::Timeout
+        } else if is_tls {
+            ErrorKind::Tls
+        } else if err.is_connect() {
+            ErrorKind::Connect
+        } else if err.is_decode() || err.is_body() {
+            ErrorKind::Decode
+        } else if err.is_builder() {
+            ErrorKind::Url
+        } else {
+            ErrorKind::Request
+        };
+
+        Self { kind, message }
+    }
+}
+
+pub fn build(data: &RequestData) -> Result<reqwest::Request, Error> {
+    let method = Method::from_bytes(data.method.as_bytes())
+        .map_err(|e| Error::new(ErrorKind::Request, e))?;
+    let url = Url::from_str(&data.url).map_err(|e| Error::new(ErrorKind::Url, e))?;
+    let mut req = reqwest::Request::new(method, url);
+
+    for header in data.enabled_headers() {
+        req.headers_mut().append(
+            HeaderName::from_bytes(header.name.as_bytes())
+                .map_err(|e| Error::new(ErrorKind::Header, e))?,
+            HeaderValue::from_str(&header.value).map_err(|e| Error::new(ErrorKind::Header, e))?,
+        );
+    }
+
+    match &data.auth {
+        AuthData::None => {}
+        AuthData::Basic { username, password } => {
+            let credentials = base64::encode(format!("{}:{}", username, password));
+            req.headers_mut().insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Basic {}", credentials))
+                    .map_err(|e| Error::new(ErrorKind::Header, e))?,
+            );
+        }
+        AuthData::Bearer { token } => {
+            req.headers_mut().insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", token))
+                    .map_err(|e| Error::new(ErrorKind::Header, e))?,
+            );
+        }
+        AuthData::ApiKeyHeader { key, value } => {
+            req.headers_mut().insert(
+                HeaderName::from_bytes(key.as_bytes())
+                    .map_err(|e| Error::new(ErrorKind::Header, e))?,
+                HeaderValue::from_str(value).map_err(|e| Error::new(ErrorKind::Header, e))?,
+            );
+        }
+        AuthData::ApiKeyQuery { key, value } => {
+            req.url_mut().query_pairs_mut().append_pair(key, value);
+        }
+    }
+
+    if !data.body.is_empty() {
+        let is_multipart = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("multipart/"));
+        // The body editor only keeps `\n`, but multipart bodies need CRLF line breaks.
+        let body = if is_multipart {
+            data.body.replace("\r\n", "\n").replace('\n', "\r\n")
+        } else {
+            data.body.clone()
+        };
+        *req.body_mut() = Some(body.into());
+    }
+
+    Ok(req)
+}
+
+pub async fn request(data: &RequestData) -> Result<Response, Error> {
+    let req = build(data)?;
     let (resp, delay) = {
         let start = Instant::now();
-        let client = reqwest::Client::new();
-        let resp = match *req.method() {
-            Method::GET => Some(client.get(req.url().as_str()).send().await?),
-            Method::POST => Some(client.post(req.url().as_str()).body(body).send().await?),
-            Method::PUT => Some(client.put(req.url().as_str()).body(body).send().await?),
-            Method::DELETE => Some(client.delete(req.url().as_str()).body(body).send().await?),
-            _ => None,
-        };
+        let client = reqwest::Client::builder()
+            .danger_accept_invalid_certs(data.insecure)
+            .timeout(TIMEOUT)
+            .build()?;
+        let resp = client.execute(req).await?;
         (resp, start.elapsed())
     };
 
-    match resp {
-        Some(resp) => Ok(Some(Response {
-            status: resp.status(),
-            header: resp
-                .headers()
+    let url = resp.url().to_string();
+    let status = resp.status();
+    let header = resp
+        .headers()
+        .iter()
+        .map(|(k, v)| {
+            (
+                k.to_string(),
+                String::from_utf8_lossy(v.as_bytes()).to_string(),
+            )
+        })
+        .collect::<Vec<(String, String)>>();
+    let content_type = resp
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+    let raw_body = resp.bytes().await?.to_vec();
+    let body_kind = BodyKind::from_content_type(content_type.as_deref(), &raw_body);
+
+    Ok(Response {
+        url,
+        status,
+        header,
+        body: renderer::render(body_kind, &raw_body),
+        raw_body,
+        body_kind,
+        delay,
+    })
+}
+
+fn content_disposition_file_name(value: &str) -> Option<String> {
+    let params = value
+        .split(';')
+        .filter_map(|param| param.split_once('='))
+        .map(|(key, value)| (key.trim().to_ascii_lowercase(), value.trim()))
+        .collect::<Vec<(String, &str)>>();
+    let extended = params
+        .iter()
+        .find(|(key, _)| key == "filename*")
+        .and_then(|(_, value)| {
+            let (_, encoded) = value.split_once("''")?;
+            Some(percent_decode(encoded))
+        });
+    extended
+        .or_else(|| {
+            params
                 .iter()
-                .map(|(k, v)| (k.to_string(), v.to_str().unwrap().to_string()))
-                .collect::<Vec<(String, String)>>(),
-            body: jsonxf::pretty_print(&resp.text().await?)?
-                .split('\n')
-                .map(|s| s.to_string())
-                .collect(),
-            delay: delay,
-        })),
-        None => Ok(None),
+                .find(|(key, _)| key == "filename")
+                .map(|(_, value)| value.trim_matches('"').to_string())
+        })
+        .filter(|name| !name.is_empty())
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
     }
+    String::from_utf8_lossy(&decoded).to_string()
 }
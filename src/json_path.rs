@@ -0,0 +1,116 @@
+use std::convert::TryFrom;
+
+use serde_json::Value;
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(i64),
+}
+
+// Supports the commonly used subset of JSONPath: `$.a.b`, `$['a b']`, `$.items[0]`, `$.items[-1]`.
+fn parse(path: &str) -> Result<Vec<Segment>, String> {
+    let mut rest = path.trim();
+    rest = rest.strip_prefix('$').unwrap_or(rest);
+    let mut segments = vec![];
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            if end == 0 {
+                return Err(format!("empty key in {}", path));
+            }
+            segments.push(Segment::Key(after[..end].to_string()));
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after
+                .find(']')
+                .ok_or_else(|| format!("unclosed bracket in {}", path))?;
+            let inner = after[..end].trim();
+            let quoted = inner
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')));
+            segments.push(match quoted {
+                Some(key) => Segment::Key(key.to_string()),
+                None => Segment::Index(
+                    inner
+                        .parse()
+                        .map_err(|_| format!("invalid index {} in {}", inner, path))?,
+                ),
+            });
+            rest = &after[end + 1..];
+        } else {
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            segments.push(Segment::Key(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+    }
+    Ok(segments)
+}
+
+pub fn select<'a>(value: &'a Value, path: &str) -> Result<Option<&'a Value>, String> {
+    let mut current = value;
+    for segment in parse(path)? {
+        let next = match (&segment, current) {
+            (Segment::Key(key), Value::Object(map)) => map.get(key),
+            (Segment::Index(i), Value::Array(items)) => {
+                let i = if *i < 0 { items.len() as i64 + i } else { *i };
+                usize::try_from(i).ok().and_then(|i| items.get(i))
+            }
+            _ => None,
+        };
+        match next {
+            Some(next) => current = next,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(current))
+}
+
+pub fn to_plain_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn key(k: &str) -> Segment {
+        Segment::Key(k.to_string())
+    }
+
+    #[test]
+    fn parse_dot_and_bracket_segments() {
+        assert_eq!(
+            parse("$.items[0].name").unwrap(),
+            vec![key("items"), Segment::Index(0), key("name")]
+        );
+        assert_eq!(
+            parse("$['a b'][\"c\"][-1]").unwrap(),
+            vec![key("a b"), key("c"), Segment::Index(-1)]
+        );
+        assert_eq!(parse("data.id").unwrap(), vec![key("data"), key("id")]);
+        assert_eq!(parse("$").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_paths() {
+        assert!(parse("$..a").is_err());
+        assert!(parse("$.items[0").is_err());
+        assert!(parse("$.items[x]").is_err());
+    }
+
+    #[test]
+    fn select_values() {
+        let value = json!({"items": [{"id": 1}, {"id": 2}], "a b": "c"});
+        assert_eq!(select(&value, "$.items[-1].id").unwrap(), Some(&json!(2)));
+        assert_eq!(select(&value, "$['a b']").unwrap(), Some(&json!("c")));
+        assert_eq!(select(&value, "$.items[5]").unwrap(), None);
+        assert_eq!(select(&value, "$.missing.id").unwrap(), None);
+    }
+}
@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use termion::event::Key;
+
+const KEYMAP_FILE: &str = "keymap.toml";
+
+static KEYMAP: OnceLock<Keymap> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    SendRequest,
+    FocusMethod,
+    FocusUrl,
+    FocusQuery,
+    FocusHeaders,
+    FocusAuth,
+    FocusBody,
+    FocusResponseBody,
+    FocusResponseHeaders,
+    FocusTests,
+    Collections,
+    History,
+    Variables,
+    Import,
+    Export,
+    HttpFile,
+    SwitchEnvironment,
+    NewTab,
+    CloseTab,
+    DuplicateTab,
+    NextTab,
+    PreviousTab,
+    Up,
+    Down,
+    Help,
+    CancelRequest,
+    Copy,
+    ToggleInsecure,
+    PageDown,
+    PageUp,
+    Top,
+    Bottom,
+    ToggleRaw,
+    CopyLine,
+    SaveBody,
+    SaveRequest,
+    SaveRequestAs,
+    Rename,
+    DuplicateRequest,
+    Delete,
+    ClearCaptured,
+    Resend,
+    ExportHar,
+    NextFormat,
+    PreviousFormat,
+    WriteHttpFile,
+    OpenHttpFile,
+    ReloadHttpFile,
+    Confirm,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    Global,
+    Sending,
+    Url,
+    ResponseBody,
+    ResponseHeaders,
+    Collections,
+    History,
+    Variables,
+    Export,
+    HttpFile,
+    Prompt,
+}
+
+impl Context {
+    const ALL: [Context; 11] = [
+        Context::Global,
+        Context::Sending,
+        Context::Url,
+        Context::ResponseBody,
+        Context::ResponseHeaders,
+        Context::Collections,
+        Context::History,
+        Context::Variables,
+        Context::Export,
+        Context::HttpFile,
+        Context::Prompt,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Context::Global => "everywhere",
+            Context::Sending => "while sending",
+            Context::Url => "URL",
+            Context::ResponseBody => "response body",
+            Context::ResponseHeaders => "response headers",
+            Context::Collections => "collections",
+            Context::History => "history",
+            Context::Variables => "variables",
+            Context::Export => "export",
+            Context::HttpFile => ".http file",
+            Context::Prompt => "prompts",
+        }
+    }
+}
+
+pub const ACTIONS: [Action; 50] = [
+    Action::Quit,
+    Action::SendRequest,
+    Action::FocusMethod,
+    Action::FocusUrl,
+    Action::FocusQuery,
+    Action::FocusHeaders,
+    Action::FocusAuth,
+    Action::FocusBody,
+    Action::FocusResponseBody,
+    Action::FocusResponseHeaders,
+    Action::FocusTests,
+    Action::Collections,
+    Action::History,
+    Action::Variables,
+    Action::Import,
+    Action::Export,
+    Action::HttpFile,
+    Action::SwitchEnvironment,
+    Action::NewTab,
+    Action::CloseTab,
+    Action::DuplicateTab,
+    Action::NextTab,
+    Action::PreviousTab,
+    Action::Up,
+    Action::Down,
+    Action::Help,
+    Action::CancelRequest,
+    Action::Copy,
+    Action::ToggleInsecure,
+    Action::PageDown,
+    Action::PageUp,
+    Action::Top,
+    Action::Bottom,
+    Action::ToggleRaw,
+    Action::CopyLine,
+    Action::SaveBody,
+    Action::SaveRequest,
+    Action::SaveRequestAs,
+    Action::Rename,
+    Action::DuplicateRequest,
+    Action::Delete,
+    Action::ClearCaptured,
+    Action::Resend,
+    Action::ExportHar,
+    Action::NextFormat,
+    Action::PreviousFormat,
+    Action::WriteHttpFile,
+    Action::OpenHttpFile,
+    Action::ReloadHttpFile,
+    Action::Confirm,
+];
+
+impl Action {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Action::Quit => "quit",
+            Action::SendRequest => "send_request",
+            Action::FocusMethod => "focus_method",
+            Action::FocusUrl => "focus_url",
+            Action::FocusQuery => "focus_query",
+            Action::FocusHeaders => "focus_headers",
+            Action::FocusAuth => "focus_auth",
+            Action::FocusBody => "focus_body",
+            Action::FocusResponseBody => "focus_response_body",
+            Action::FocusResponseHeaders => "focus_response_headers",
+            Action::FocusTests => "focus_tests",
+            Action::Collections => "collections",
+            Action::History => "history",
+            Action::Variables => "variables",
+            Action::Import => "import",
+            Action::Export => "export",
+            Action::HttpFile => "http_file",
+            Action::SwitchEnvironment => "switch_environment",
+            Action::NewTab => "new_tab",
+            Action::CloseTab => "close_tab",
+            Action::DuplicateTab => "duplicate_tab",
+            Action::NextTab => "next_tab",
+            Action::PreviousTab => "previous_tab",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Help => "help",
+            Action::CancelRequest => "cancel_request",
+            Action::Copy => "copy",
+            Action::ToggleInsecure => "toggle_insecure",
+            Action::PageDown => "page_down",
+            Action::PageUp => "page_up",
+            Action::Top => "top",
+            Action::Bottom => "bottom",
+            Action::ToggleRaw => "toggle_raw",
+            Action::CopyLine => "copy_line",
+            Action::SaveBody => "save_body",
+            Action::SaveRequest => "save_request",
+            Action::SaveRequestAs => "save_request_as",
+            Action::Rename => "rename",
+            Action::DuplicateRequest => "duplicate_request",
+            Action::Delete => "delete",
+            Action::ClearCaptured => "clear_captured",
+            Action::Resend => "resend",
+            Action::ExportHar => "export_har",
+            Action::NextFormat => "next_format",
+            Action::PreviousFormat => "previous_format",
+            Action::WriteHttpFile => "write_http_file",
+            Action::OpenHttpFile => "open_http_file",
+            Action::ReloadHttpFile => "reload_http_file",
+            Action::Confirm => "confirm",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match *self {
+            Action::Quit => "quit",
+            Action::SendRequest => "send request",
+            Action::FocusMethod => "edit method",
+            Action::FocusUrl => "edit URL",
+            Action::FocusQuery => "edit query",
+            Action::FocusHeaders => "edit headers",
+            Action::FocusAuth => "edit auth",
+            Action::FocusBody => "edit request body",
+            Action::FocusResponseBody => "response body",
+            Action::FocusResponseHeaders => "response headers",
+            Action::FocusTests => "response tests",
+            Action::Collections => "collections",
+            Action::History => "history",
+            Action::Variables => "variables",
+            Action::Import => "import curl",
+            Action::Export => "export",
+            Action::HttpFile => ".http file",
+            Action::SwitchEnvironment => "switch environment",
+            Action::NewTab => "new tab",
+            Action::CloseTab => "close tab",
+            Action::DuplicateTab => "duplicate tab",
+            Action::NextTab => "next tab",
+            Action::PreviousTab => "previous tab",
+            Action::Up => "move up in lists",
+            Action::Down => "move down in lists",
+            Action::Help => "key bindings",
+            Action::CancelRequest => "cancel the running request",
+            Action::Copy => "copy",
+            Action::ToggleInsecure => "skip TLS verification",
+            Action::PageDown => "page down",
+            Action::PageUp => "page up",
+            Action::Top => "jump to the top",
+            Action::Bottom => "jump to the bottom",
+            Action::ToggleRaw => "raw / pretty body",
+            Action::CopyLine => "copy the top line",
+            Action::SaveBody => "save body to a file",
+            Action::SaveRequest => "save request",
+            Action::SaveRequestAs => "save request as",
+            Action::Rename => "rename",
+            Action::DuplicateRequest => "duplicate request",
+            Action::Delete => "delete",
+            Action::ClearCaptured => "clear captured variables",
+            Action::Resend => "re-send",
+            Action::ExportHar => "export HAR",
+            Action::NextFormat => "next export format",
+            Action::PreviousFormat => "previous export format",
+            Action::WriteHttpFile => "write back",
+            Action::OpenHttpFile => "open file",
+            Action::ReloadHttpFile => "reload",
+            Action::Confirm => "answer yes to a prompt",
+        }
+    }
+
+    // Keys only clash between actions that share a context; the panes handle their own keys
+    // before falling back to the global ones.
+    pub fn contexts(&self) -> &'static [Context] {
+        match *self {
+            Action::Up | Action::Down => &Context::ALL,
+            Action::CancelRequest => &[Context::Sending],
+            Action::Copy => &[
+                Context::Url,
+                Context::ResponseBody,
+                Context::ResponseHeaders,
+                Context::Export,
+            ],
+            Action::ToggleInsecure => &[Context::Url],
+            Action::PageDown => &[Context::ResponseBody],
+            Action::PageUp => &[Context::ResponseBody],
+            Action::Top => &[Context::ResponseBody],
+            Action::Bottom => &[Context::ResponseBody],
+            Action::ToggleRaw => &[Context::ResponseBody],
+            Action::CopyLine => &[Context::ResponseBody],
+            Action::SaveBody => &[Context::ResponseBody],
+            Action::SaveRequest => &[Context::Collections],
+            Action::SaveRequestAs => &[Context::Collections],
+            Action::Rename => &[Context::Collections],
+            Action::DuplicateRequest => &[Context::Collections],
+            Action::Delete => &[Context::Collections, Context::Variables],
+            Action::ClearCaptured => &[Context::Variables],
+            Action::Resend => &[Context::History],
+            Action::ExportHar => &[Context::History],
+            Action::NextFormat => &[Context::Export],
+            Action::PreviousFormat => &[Context::Export],
+            Action::WriteHttpFile => &[Context::HttpFile],
+            Action::OpenHttpFile => &[Context::HttpFile],
+            Action::ReloadHttpFile => &[Context::HttpFile],
+            Action::Confirm => &[Context::Prompt],
+            _ => &[Context::Global],
+        }
+    }
+
+    fn default_keys(&self) -> Vec<Key> {
+        match *self {
+            Action::Quit => vec![Key::Ctrl('c')],
+            Action::SendRequest => vec![Key::Ctrl('s')],
+            Action::FocusMethod => vec![Key::Char('m')],
+            Action::FocusUrl => vec![Key::Char('u')],
+            Action::FocusQuery => vec![Key::Char('q')],
+            Action::FocusHeaders => vec![Key::Char('e')],
+            Action::FocusAuth => vec![Key::Char('a')],
+            Action::FocusBody => vec![Key::Char('r')],
+            Action::FocusResponseBody => vec![Key::Char('b')],
+            Action::FocusResponseHeaders => vec![Key::Char('h')],
+            Action::FocusTests => vec![Key::Char('t')],
+            Action::Collections => vec![Key::Char('c')],
+            Action::History => vec![Key::Char('H')],
+            Action::Variables => vec![Key::Char('V')],
+            Action::Import => vec![Key::Char('I')],
+            Action::Export => vec![Key::Char('X')],
+            Action::HttpFile => vec![Key::Char('F')],
+            Action::SwitchEnvironment => vec![Key::Char('E')],
+            Action::NewTab => vec![Key::Char('T')],
+            Action::CloseTab => vec![Key::Char('W')],
+            Action::DuplicateTab => vec![Key::Char('D')],
+            Action::NextTab => vec![Key::Char(']')],
+            Action::PreviousTab => vec![Key::Char('[')],
+            Action::Up => vec![Key::Char('k'), Key::Up],
+            Action::Down => vec![Key::Char('j'), Key::Down],
+            Action::Help => vec![Key::Char('?')],
+            Action::CancelRequest => vec![Key::Ctrl('c'), Key::Esc],
+            Action::Copy => vec![Key::Char('y')],
+            Action::ToggleInsecure => vec![Key::Char('K')],
+            Action::PageDown => vec![Key::PageDown, Key::Ctrl('d')],
+            Action::PageUp => vec![Key::PageUp, Key::Ctrl('u')],
+            Action::Top => vec![Key::Char('g')],
+            Action::Bottom => vec![Key::Char('G')],
+            Action::ToggleRaw => vec![Key::Char('p')],
+            Action::CopyLine => vec![Key::Char('Y')],
+            Action::SaveBody => vec![Key::Char('s')],
+            Action::SaveRequest => vec![Key::Char('w')],
+            Action::SaveRequestAs => vec![Key::Char('n')],
+            Action::Rename => vec![Key::Char('r')],
+            Action::DuplicateRequest => vec![Key::Char('d')],
+            Action::Delete => vec![Key::Char('x')],
+            Action::ClearCaptured => vec![Key::Char('X')],
+            Action::Resend => vec![Key::Char('s')],
+            Action::ExportHar => vec![Key::Char('x')],
+            Action::NextFormat => vec![Key::Right, Key::Char('l'), Key::Char('\t')],
+            Action::PreviousFormat => vec![Key::Left, Key::Char('h'), Key::BackTab],
+            Action::WriteHttpFile => vec![Key::Char('w')],
+            Action::OpenHttpFile => vec![Key::Char('o')],
+            Action::ReloadHttpFile => vec![Key::Char('R')],
+            Action::Confirm => vec![Key::Char('y')],
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Keys {
+    One(String),
+    Many(Vec<String>),
+}
+
+pub struct Keymap {
+    bindings: HashMap<Action, Vec<Key>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: ACTIONS
+                .iter()
+                .map(|action| (*action, action.default_keys()))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("wica").join(KEYMAP_FILE))
+    }
+
+    pub fn load() -> Result<Self> {
+        match Self::path().filter(|path| path.exists()) {
+            Some(path) => Self::parse(&fs::read_to_string(&path)?)
+                .map_err(|err| anyhow!("{}: {}", path.display(), err)),
+            None => Ok(Self::default()),
+        }
+    }
+
+    // Every action listed in the file replaces all of its default keys.
+    fn parse(text: &str) -> Result<Self> {
+        let overrides: HashMap<String, Keys> = toml::from_str(text)?;
+        let mut keymap = Self::default();
+        for (name, keys) in overrides {
+            let action = ACTIONS
+                .iter()
+                .find(|action| action.name() == name)
+                .ok_or_else(|| anyhow!("unknown action {}", name))?;
+            let keys = match keys {
+                Keys::One(key) => vec![key],
+                Keys::Many(keys) => keys,
+            };
+            let keys = keys
+                .iter()
+                .map(|key| parse_key(key).ok_or_else(|| anyhow!("unknown key {}", key)))
+                .collect::<Result<Vec<Key>>>()?;
+            keymap.bindings.insert(*action, keys);
+        }
+
+        let mut bound: HashMap<Key, Vec<Action>> = HashMap::new();
+        for action in ACTIONS.iter() {
+            for key in keymap.keys(*action) {
+                let actions = bound.entry(*key).or_default();
+                let other = actions.iter().find(|other| {
+                    other
+                        .contexts()
+                        .iter()
+                        .any(|context| action.contexts().contains(context))
+                });
+                if let Some(other) = other {
+                    return Err(anyhow!(
+                        "{} is bound to both {} and {}",
+                        key_label(*key),
+                        other.name(),
+                        action.name()
+                    ));
+                }
+                actions.push(*action);
+            }
+        }
+        Ok(keymap)
+    }
+
+    pub fn keys(&self, action: Action) -> &[Key] {
+        self.bindings
+            .get(&action)
+            .map(|keys| keys.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn action(&self, k: Key) -> Option<Action> {
+        ACTIONS
+            .iter()
+            .filter(|action| action.contexts().contains(&Context::Global))
+            .find(|action| self.keys(**action).contains(&k))
+            .copied()
+    }
+
+    pub fn is(&self, action: Action, k: Key) -> bool {
+        self.keys(action).contains(&k)
+    }
+
+    // The first key only, for hints with little room.
+    pub fn short_label(&self, action: Action) -> String {
+        self.keys(action)
+            .first()
+            .map_or_else(|| "unbound".to_string(), |key| key_label(*key))
+    }
+
+    pub fn title(&self, action: Action, name: &str) -> String {
+        format!("[{}]{}", self.label(action), name)
+    }
+
+    pub fn label(&self, action: Action) -> String {
+        let keys = self.keys(action);
+        if keys.is_empty() {
+            "unbound".to_string()
+        } else {
+            keys.iter()
+                .map(|key| key_label(*key))
+                .collect::<Vec<String>>()
+                .join("/")
+        }
+    }
+}
+
+pub fn init(keymap: Keymap) {
+    if KEYMAP.set(keymap).is_err() {
+        eprintln!("key bindings were already loaded");
+    }
+}
+
+pub fn get() -> &'static Keymap {
+    KEYMAP.get_or_init(Keymap::default)
+}
+
+fn parse_key(text: &str) -> Option<Key> {
+    let mut chars = text.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(Key::Char(c));
+    }
+    let single = |rest: &str| {
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    };
+    let lower = text.to_lowercase();
+    if let Some(rest) = lower.strip_prefix("ctrl-") {
+        return single(rest).map(Key::Ctrl);
+    }
+    if lower.starts_with("alt-") {
+        return single(&text["alt-".len()..]).map(Key::Alt);
+    }
+    if let Some(n) = lower.strip_prefix('f').and_then(|n| n.parse().ok()) {
+        return Some(Key::F(n));
+    }
+    Some(match lower.as_str() {
+        "esc" => Key::Esc,
+        "enter" => Key::Char('\n'),
+        "tab" => Key::Char('\t'),
+        "backtab" => Key::BackTab,
+        "space" => Key::Char(' '),
+        "backspace" => Key::Backspace,
+        "delete" => Key::Delete,
+        "insert" => Key::Insert,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        _ => return None,
+    })
+}
+
+pub fn key_label(key: Key) -> String {
+    match key {
+        Key::Char('\n') => "Enter".to_string(),
+        Key::Char('\t') => "Tab".to_string(),
+        Key::Char(' ') => "Space".to_string(),
+        Key::Char(c) => c.to_string(),
+        Key::Ctrl(c) => format!("Ctrl + {}", c),
+        Key::Alt(c) => format!("Alt + {}", c),
+        Key::F(n) => format!("F{}", n),
+        Key::Esc => "Esc".to_string(),
+        Key::BackTab => "Shift + Tab".to_string(),
+        Key::Backspace => "Backspace".to_string(),
+        Key::Delete => "Delete".to_string(),
+        Key::Insert => "Insert".to_string(),
+        Key::Up => "↑".to_string(),
+        Key::Down => "↓".to_string(),
+        Key::Left => "←".to_string(),
+        Key::Right => "→".to_string(),
+        Key::Home => "Home".to_string(),
+        Key::End => "End".to_string(),
+        Key::PageUp => "PageUp".to_string(),
+        Key::PageDown => "PageDown".to_string(),
+        _ => format!("{:?}", key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_key_names() {
+        assert_eq!(parse_key("x"), Some(Key::Char('x')));
+        assert_eq!(parse_key("X"), Some(Key::Char('X')));
+        assert_eq!(parse_key("ctrl-s"), Some(Key::Ctrl('s')));
+        assert_eq!(parse_key("Ctrl-S"), Some(Key::Ctrl('s')));
+        assert_eq!(parse_key("alt-K"), Some(Key::Alt('K')));
+        assert_eq!(parse_key("F5"), Some(Key::F(5)));
+        assert_eq!(parse_key("Enter"), Some(Key::Char('\n')));
+        assert_eq!(parse_key("space"), Some(Key::Char(' ')));
+        assert_eq!(parse_key("PageDown"), Some(Key::PageDown));
+        assert_eq!(parse_key("ctrl-ab"), None);
+        assert_eq!(parse_key("hyper-x"), None);
+        assert_eq!(parse_key(""), None);
+    }
+
+    #[test]
+    fn default_bindings_do_not_clash() {
+        assert!(Keymap::parse("").is_ok());
+    }
+
+    #[test]
+    fn parse_overrides_default_keys() {
+        let keymap = Keymap::parse("send_request = \"F5\"\nup = [\"k\", \"ctrl-p\"]").unwrap();
+        assert_eq!(keymap.keys(Action::SendRequest), &[Key::F(5)]);
+        assert_eq!(keymap.keys(Action::Up), &[Key::Char('k'), Key::Ctrl('p')]);
+        assert_eq!(keymap.action(Key::F(5)), Some(Action::SendRequest));
+        assert_eq!(keymap.action(Key::Ctrl('s')), None);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_actions_and_keys() {
+        assert!(Keymap::parse("launch_rockets = \"x\"").is_err());
+        assert!(Keymap::parse("quit = \"hyper-q\"").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_keys_bound_twice_in_one_context() {
+        assert!(Keymap::parse("new_tab = \"m\"").is_err());
+        assert!(Keymap::parse("rename = \"d\"").is_err());
+        assert!(Keymap::parse("copy = \"j\"").is_err());
+        // Pane keys may shadow global ones.
+        assert!(Keymap::parse("rename = \"T\"").is_ok());
+        assert!(Keymap::parse("resend = \"w\"").is_ok());
+    }
+
+    #[test]
+    fn action_ignores_pane_keys() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.action(Key::Char('r')), Some(Action::FocusBody));
+        assert_eq!(keymap.action(Key::Char('y')), None);
+        assert_eq!(keymap.action(Key::Ctrl('c')), Some(Action::Quit));
+    }
+}
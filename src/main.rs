@@ -1,12 +1,32 @@
 mod app;
+mod assertion;
+mod capture;
 mod cli;
+mod clipboard;
+mod collection;
 mod components;
+mod curl;
+mod environment;
 mod event;
+mod har;
+mod history;
+mod http_file;
 mod http_request;
+mod json_path;
+mod keymap;
+mod openapi;
+mod postman;
+mod renderer;
+mod request_data;
+mod runner;
+mod snippet;
+mod template;
 mod ui;
+mod workspace;
 
 use app::App;
-use event::Event;
+use cli::{Cli, Command};
+use event::{Event, RequestOrigin};
 
 use anyhow::Result;
 use std::io;
@@ -16,7 +36,106 @@ use tui::{backend::TermionBackend, Terminal};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let _cmd_args = crate::cli::Cli::from_args();
+    let cli = Cli::from_args();
+    match &cli.command {
+        Some(Command::Run { target, env }) => {
+            if !runner::run(target, env.as_deref()).await? {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::ImportHar { file, collection }) => {
+            match collection {
+                Some(folder) => {
+                    let report = har::import_to_collection(file, folder)?;
+                    println!(
+                        "Imported {} entries from {}",
+                        report.requests,
+                        file.display()
+                    );
+                    if !report.renamed.is_empty() {
+                        println!("Saved under a new name to keep existing requests:");
+                        for renamed in &report.renamed {
+                            println!("  {}", renamed);
+                        }
+                    }
+                }
+                None => {
+                    let count = har::import_to_history(file)?;
+                    println!("Imported {} entries from {}", count, file.display());
+                }
+            }
+            return Ok(());
+        }
+        Some(Command::ImportPostman {
+            file,
+            environments,
+            collection,
+        }) => {
+            let report = postman::import(file, environments, collection.as_deref())?;
+            println!(
+                "Imported {} requests from {}",
+                report.requests,
+                file.display()
+            );
+            for name in &report.environments {
+                println!("Imported environment {}", name);
+            }
+            if !report.skipped.is_empty() {
+                println!("Could not translate:");
+                for skipped in &report.skipped {
+                    println!("  {}", skipped);
+                }
+            }
+            if !report.renamed.is_empty() {
+                println!("Saved under a new name to keep existing requests:");
+                for renamed in &report.renamed {
+                    println!("  {}", renamed);
+                }
+            }
+            return Ok(());
+        }
+        Some(Command::ImportOpenapi { file, collection }) => {
+            let report = openapi::import(file, collection.as_deref())?;
+            println!(
+                "Imported {} requests from {}",
+                report.requests,
+                file.display()
+            );
+            if let Some(name) = &report.environment {
+                println!("Set baseUrl in environment {}", name);
+            }
+            if !report.skipped.is_empty() {
+                println!("Could not translate:");
+                for skipped in &report.skipped {
+                    println!("  {}", skipped);
+                }
+            }
+            if !report.renamed.is_empty() {
+                println!("Saved under a new name to keep existing requests:");
+                for renamed in &report.renamed {
+                    println!("  {}", renamed);
+                }
+            }
+            return Ok(());
+        }
+        Some(Command::ExportHar { file, limit }) => {
+            let store = history::HistoryStore::load();
+            let entries = store.entries();
+            let skip = limit.map_or(0, |limit| entries.len().saturating_sub(limit));
+            har::export(file, &entries[skip..].iter().collect::<Vec<_>>())?;
+            println!(
+                "Exported {} entries to {}",
+                entries.len() - skip,
+                file.display()
+            );
+            return Ok(());
+        }
+        None => {}
+    }
+
+    // Loaded before entering raw mode so a broken config is reported on a usable terminal.
+    keymap::init(keymap::Keymap::load()?);
 
     let stdout = io::stdout().into_raw_mode()?;
     let stdout = MouseTerminal::from(stdout);
@@ -26,6 +145,12 @@ async fn main() -> Result<()> {
     terminal.clear()?;
 
     let mut app = App::default();
+    if let Some(data) = cli.request_data() {
+        app.load_request_handle(data, RequestOrigin::Other);
+    }
+    if let Some(path) = &cli.http_file {
+        app.open_http_file(path);
+    }
 
     loop {
         terminal.draw(|f| {
@@ -36,13 +161,47 @@ async fn main() -> Result<()> {
             Event::KeyInput(input) => {
                 app.key_handle(input)?;
             }
-            Event::Request => match app.request_handle().await {
-                Ok(_) => {}
-                // TODO: response fieldにerror_messageを表示する
-                Err(_e) => {}
-            },
-            Event::Response(resp) => {
-                app.response_handle(resp);
+            Event::Request => {
+                app.request_handle();
+            }
+            Event::ResendRequest(data) => {
+                app.resend_handle(data);
+            }
+            Event::LoadRequest(data, origin) => {
+                app.load_request_handle(data, origin);
+            }
+            Event::SaveRequest(path) => {
+                app.save_request_handle(path);
+            }
+            Event::SavedRequestRenamed(from, to) => {
+                app.saved_request_renamed_handle(from, to);
+            }
+            Event::SavedRequestDeleted(path) => {
+                app.saved_request_deleted_handle(path);
+            }
+            Event::WriteHttpFile => {
+                app.write_http_file_handle();
+            }
+            Event::SwitchEnvironment => {
+                app.switch_environment_handle();
+            }
+            Event::NewTab => {
+                app.new_tab_handle();
+            }
+            Event::CloseTab => {
+                app.close_tab_handle();
+            }
+            Event::DuplicateTab => {
+                app.duplicate_tab_handle();
+            }
+            Event::SwitchTab(offset) => {
+                app.switch_tab_handle(offset);
+            }
+            Event::Response(tab_id, request_id, resp) => {
+                app.response_handle(tab_id, request_id, resp);
+            }
+            Event::RequestError(tab_id, request_id, e) => {
+                app.request_error_handle(tab_id, request_id, e);
             }
             Event::SetQuery(query) => {
                 app.set_query_handle(query);
@@ -50,6 +209,12 @@ async fn main() -> Result<()> {
             Event::ChangeFocus(position) => {
                 app.change_focus(position);
             }
+            Event::Copy(text) => {
+                app.copy_handle(text);
+            }
+            Event::Notify(message) => {
+                app.notify_handle(message);
+            }
             Event::Tick => continue,
             Event::Quit => break,
         }
@@ -0,0 +1,488 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Map, Value};
+
+use crate::collection::{self, CollectionStore, SavedRequest};
+use crate::curl::{multipart_body, urlencode, FORM_BOUNDARY};
+use crate::environment::EnvironmentStore;
+use crate::request_data::{AuthData, HeaderData, RequestData};
+
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+const BASE_URL_VARIABLE: &str = "baseUrl";
+// Guards against self-referencing schemas when building example bodies.
+const MAX_SCHEMA_DEPTH: usize = 8;
+
+#[derive(Default)]
+pub struct Report {
+    pub requests: usize,
+    pub environment: Option<String>,
+    pub skipped: Vec<String>,
+    pub renamed: Vec<String>,
+}
+
+struct Importer<'a> {
+    spec: &'a Value,
+    store: CollectionStore,
+    used: HashSet<PathBuf>,
+    report: Report,
+}
+
+fn value_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+impl<'a> Importer<'a> {
+    // Follows local `$ref`s such as `#/components/schemas/Pet`.
+    fn resolve(&self, value: &'a Value) -> &'a Value {
+        let mut value = value;
+        let mut seen = 0;
+        while let Some(reference) = value.get("$ref").and_then(|r| r.as_str()) {
+            match reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.spec.pointer(pointer))
+            {
+                Some(target) if seen < MAX_SCHEMA_DEPTH => value = target,
+                _ => return &Value::Null,
+            }
+            seen += 1;
+        }
+        value
+    }
+
+    fn example(&self, schema: &Value, depth: usize) -> Value {
+        let schema = self.resolve(schema);
+        if depth > MAX_SCHEMA_DEPTH {
+            return Value::Null;
+        }
+        if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+            return example.clone();
+        }
+        if let Some(value) = schema
+            .get("enum")
+            .and_then(|values| values.as_array())
+            .and_then(|values| values.first())
+        {
+            return value.clone();
+        }
+        if let Some(schemas) = schema.get("allOf").and_then(|s| s.as_array()) {
+            let mut merged = Map::new();
+            for schema in schemas {
+                match self.example(schema, depth + 1) {
+                    Value::Object(object) => merged.extend(object),
+                    value if schemas.len() == 1 => return value,
+                    _ => {}
+                }
+            }
+            return Value::Object(merged);
+        }
+        if let Some(schema) = ["oneOf", "anyOf"]
+            .iter()
+            .filter_map(|key| schema.get(key).and_then(|s| s.as_array()))
+            .find_map(|schemas| schemas.first())
+        {
+            return self.example(schema, depth + 1);
+        }
+
+        let kind = match schema.get("type") {
+            Some(Value::String(kind)) => kind.as_str(),
+            // OpenAPI 3.1 allows a list of types, e.g. `[string, "null"]`.
+            Some(Value::Array(kinds)) => kinds
+                .iter()
+                .filter_map(|kind| kind.as_str())
+                .find(|kind| *kind != "null")
+                .unwrap_or("null"),
+            _ if schema.get("properties").is_some() => "object",
+            _ if schema.get("items").is_some() => "array",
+            _ => "",
+        };
+        match kind {
+            "object" => {
+                let mut object = Map::new();
+                if let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) {
+                    for (name, property) in properties {
+                        if self.resolve(property).get("readOnly") == Some(&Value::Bool(true)) {
+                            continue;
+                        }
+                        object.insert(name.clone(), self.example(property, depth + 1));
+                    }
+                }
+                Value::Object(object)
+            }
+            "array" => match schema.get("items") {
+                Some(items) => json!([self.example(items, depth + 1)]),
+                None => json!([]),
+            },
+            "string" => json!(match schema.get("format").and_then(|f| f.as_str()) {
+                Some("date-time") => "2022-01-01T00:00:00Z",
+                Some("date") => "2022-01-01",
+                Some("email") => "user@example.com",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("uri") | Some("url") => "https://example.com",
+                _ => "string",
+            }),
+            "integer" => json!(0),
+            "number" => json!(0.0),
+            "boolean" => json!(false),
+            _ => Value::Null,
+        }
+    }
+
+    // Parameters without a usable example become `{{name}}` so an environment can fill them in.
+    fn parameter_value(&self, parameter: &Value, name: &str) -> String {
+        let example = parameter.get("example").cloned().or_else(|| {
+            parameter
+                .get("examples")
+                .and_then(|examples| examples.as_object())
+                .and_then(|examples| examples.values().next())
+                .map(|example| self.resolve(example))
+                .and_then(|example| example.get("value").cloned())
+        });
+        let example = example.or_else(|| {
+            parameter.get("schema").map(|schema| {
+                let schema = self.resolve(schema);
+                if schema.get("example").is_some()
+                    || schema.get("default").is_some()
+                    || schema.get("enum").is_some()
+                {
+                    self.example(schema, 0)
+                } else {
+                    Value::Null
+                }
+            })
+        });
+        match example {
+            Some(Value::Null) | None => format!("{{{{{}}}}}", name),
+            Some(Value::Array(values)) => values
+                .iter()
+                .map(value_string)
+                .collect::<Vec<String>>()
+                .join(","),
+            Some(value) => value_string(&value),
+        }
+    }
+
+    fn media_example(&self, media: &Value) -> Value {
+        if let Some(example) = media.get("example") {
+            return example.clone();
+        }
+        if let Some(example) = media
+            .get("examples")
+            .and_then(|examples| examples.as_object())
+            .and_then(|examples| examples.values().next())
+            .map(|example| self.resolve(example))
+            .and_then(|example| example.get("value"))
+        {
+            return example.clone();
+        }
+        media
+            .get("schema")
+            .map(|schema| self.example(schema, 0))
+            .unwrap_or(Value::Null)
+    }
+
+    fn body(&mut self, operation: &'a Value, data: &mut RequestData, location: &str) {
+        let content = match operation
+            .get("requestBody")
+            .map(|body| self.resolve(body))
+            .and_then(|body| body.get("content"))
+            .and_then(|content| content.as_object())
+        {
+            Some(content) => content,
+            None => return,
+        };
+        let (media_type, media) = match content
+            .iter()
+            .find(|(media_type, _)| media_type.contains("json"))
+            .or_else(|| content.iter().next())
+        {
+            Some(media) => media,
+            None => return,
+        };
+        let example = self.media_example(media);
+
+        let (body, content_type) = match media_type.as_str() {
+            t if t.contains("json") => (
+                serde_json::to_string_pretty(&example).unwrap_or_default(),
+                media_type.clone(),
+            ),
+            "application/x-www-form-urlencoded" => (
+                fields(&example)
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", urlencode(name), urlencode(value)))
+                    .collect::<Vec<String>>()
+                    .join("&"),
+                media_type.clone(),
+            ),
+            "multipart/form-data" => (
+                multipart_body(&fields(&example)),
+                format!("multipart/form-data; boundary={}", FORM_BOUNDARY),
+            ),
+            t if t.starts_with("text/") || t.contains("xml") => {
+                (value_string(&example), media_type.clone())
+            }
+            _ => {
+                self.skip(location, format!("{} request body", media_type));
+                return;
+            }
+        };
+        data.body = body;
+        data.headers.push(HeaderData {
+            name: "Content-Type".to_string(),
+            value: content_type,
+            enabled: true,
+        });
+    }
+
+    fn security(&mut self, operation: &Value, location: &str) -> AuthData {
+        let requirements = operation
+            .get("security")
+            .or_else(|| self.spec.get("security"))
+            .and_then(|security| security.as_array());
+        // The first requirement is used; an empty one means the operation needs no auth.
+        let name = match requirements
+            .and_then(|requirements| requirements.first())
+            .and_then(|requirement| requirement.as_object())
+            .and_then(|requirement| requirement.keys().next())
+        {
+            Some(name) => name,
+            None => return AuthData::None,
+        };
+        let scheme = self.resolve(
+            self.spec
+                .pointer(&format!("/components/securitySchemes/{}", name))
+                .unwrap_or(&Value::Null),
+        );
+        let field = |key: &str| {
+            scheme
+                .get(key)
+                .and_then(|value| value.as_str())
+                .unwrap_or_default()
+        };
+        match (
+            field("type"),
+            field("scheme").to_lowercase().as_str(),
+            field("in"),
+        ) {
+            ("http", "basic", _) => AuthData::Basic {
+                username: "{{username}}".to_string(),
+                password: "{{password}}".to_string(),
+            },
+            ("http", "bearer", _) | ("oauth2", _, _) | ("openIdConnect", _, _) => {
+                AuthData::Bearer {
+                    token: "{{token}}".to_string(),
+                }
+            }
+            ("apiKey", _, "header") => AuthData::ApiKeyHeader {
+                key: field("name").to_string(),
+                value: format!("{{{{{}}}}}", name),
+            },
+            ("apiKey", _, "query") => AuthData::ApiKeyQuery {
+                key: field("name").to_string(),
+                value: format!("{{{{{}}}}}", name),
+            },
+            (kind, _, _) => {
+                self.skip(location, format!("{} security scheme {}", kind, name));
+                AuthData::None
+            }
+        }
+    }
+
+    fn skip(&mut self, location: &str, what: impl AsRef<str>) {
+        self.report
+            .skipped
+            .push(format!("{}: {}", location, what.as_ref()));
+    }
+
+    fn operation(
+        &mut self,
+        method: &str,
+        route: &str,
+        path_item: &'a Value,
+        operation: &'a Value,
+        dir: &Path,
+    ) -> Result<()> {
+        let location = format!("{} {}", method.to_uppercase(), route);
+
+        // Operation parameters override path item parameters with the same name and location.
+        let mut parameters = BTreeMap::new();
+        for parameter in [path_item, operation]
+            .iter()
+            .filter_map(|item| item.get("parameters").and_then(|p| p.as_array()))
+            .flatten()
+        {
+            let parameter = self.resolve(parameter);
+            let key = |field: &str| {
+                parameter
+                    .get(field)
+                    .and_then(|value| value.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            parameters.insert((key("in"), key("name")), parameter);
+        }
+
+        let mut url = format!("{{{{{}}}}}{}", BASE_URL_VARIABLE, route);
+        let mut query = vec![];
+        let mut headers = vec![];
+        let mut cookies = vec![];
+        for ((place, name), parameter) in &parameters {
+            let required = parameter.get("required") == Some(&Value::Bool(true));
+            let value = self.parameter_value(parameter, name);
+            let has_example = !value.starts_with("{{");
+            match place.as_str() {
+                "path" => url = url.replace(&format!("{{{}}}", name), &value),
+                "query" if required || has_example => query.push(format!("{}={}", name, value)),
+                "header" if required || has_example => headers.push(HeaderData {
+                    name: name.clone(),
+                    value,
+                    enabled: required,
+                }),
+                "cookie" if required => cookies.push(format!("{}={}", name, value)),
+                "query" | "header" | "cookie" => {}
+                place => self.skip(&location, format!("{} parameter {}", place, name)),
+            }
+        }
+        if !query.is_empty() {
+            url = format!("{}?{}", url, query.join("&"));
+        }
+        if !cookies.is_empty() {
+            headers.push(HeaderData {
+                name: "Cookie".to_string(),
+                value: cookies.join("; "),
+                enabled: true,
+            });
+        }
+
+        let mut data = RequestData {
+            method: method.to_uppercase(),
+            url,
+            headers,
+            ..RequestData::default()
+        };
+        self.body(operation, &mut data, &location);
+        data.auth = self.security(operation, &location);
+
+        let name = ["operationId", "summary"]
+            .iter()
+            .find_map(|key| operation.get(key).and_then(|value| value.as_str()))
+            .map(|name| name.to_string())
+            .unwrap_or(location);
+        let (path, collided) = self.store.unique_path(dir, &name, &mut self.used);
+        if collided {
+            self.report.renamed.push(path.display().to_string());
+        }
+        self.store.save(
+            &path,
+            &SavedRequest {
+                request: data,
+                ..SavedRequest::default()
+            },
+        )?;
+        self.report.requests += 1;
+        Ok(())
+    }
+}
+
+fn fields(example: &Value) -> Vec<(String, String)> {
+    example
+        .as_object()
+        .map(|object| {
+            object
+                .iter()
+                .map(|(name, value)| (name.clone(), value_string(value)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Server URLs may contain `{variable}`s, which are replaced by their defaults.
+fn server_url(spec: &Value) -> Option<String> {
+    let server = spec.get("servers")?.as_array()?.first()?;
+    let mut url = server.get("url")?.as_str()?.to_string();
+    if let Some(variables) = server.get("variables").and_then(|v| v.as_object()) {
+        for (name, variable) in variables {
+            let default = variable
+                .get("default")
+                .map(value_string)
+                .unwrap_or_default();
+            url = url.replace(&format!("{{{}}}", name), &default);
+        }
+    }
+    Some(url.trim_end_matches('/').to_string())
+}
+
+pub fn import(path: &Path, folder: Option<&Path>) -> Result<Report> {
+    // YAML is a superset of JSON, so one parser handles both formats.
+    let spec: Value = serde_yaml::from_str(&fs::read_to_string(path)?)
+        .map_err(|err| anyhow!("{} is not a YAML or JSON document: {}", path.display(), err))?;
+    match spec.get("openapi").map(value_string) {
+        Some(version) if version.starts_with('3') => {}
+        _ => return Err(anyhow!("{} is not an OpenAPI 3 document", path.display())),
+    }
+
+    let title = spec
+        .pointer("/info/title")
+        .and_then(|title| title.as_str())
+        .unwrap_or("openapi")
+        .to_string();
+    let root = folder
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from(collection::file_name(&title)));
+
+    let mut importer = Importer {
+        spec: &spec,
+        store: CollectionStore::default(),
+        used: HashSet::new(),
+        report: Report::default(),
+    };
+    if let Some(paths) = spec.get("paths").and_then(|paths| paths.as_object()) {
+        for (route, path_item) in paths {
+            let path_item = importer.resolve(path_item);
+            for method in METHODS.iter() {
+                let operation = match path_item.get(method) {
+                    Some(operation) => operation,
+                    None => continue,
+                };
+                let dir = match operation
+                    .get("tags")
+                    .and_then(|tags| tags.as_array())
+                    .and_then(|tags| tags.first())
+                    .and_then(|tag| tag.as_str())
+                {
+                    Some(tag) => root.join(collection::file_name(tag)),
+                    None => root.clone(),
+                };
+                importer.operation(method, route, path_item, operation, &dir)?;
+            }
+        }
+    }
+    if spec.get("webhooks").is_some() {
+        importer
+            .report
+            .skipped
+            .push("webhooks are not requests".to_string());
+    }
+
+    let mut report = importer.report;
+    if let Some(url) = server_url(&spec) {
+        let mut store = EnvironmentStore::load()?;
+        let vars = BTreeMap::from([(BASE_URL_VARIABLE.to_string(), url)]);
+        store.set_variables(&title, vars)?;
+        report.environment = Some(title);
+    } else {
+        report.skipped.push(format!(
+            "no server URL, set {{{{{}}}}} in an environment",
+            BASE_URL_VARIABLE
+        ));
+    }
+    Ok(report)
+}
@@ -0,0 +1,521 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::collection::{self, CollectionStore, SavedRequest};
+use crate::curl::{multipart_body, urlencode, FORM_BOUNDARY};
+use crate::environment::EnvironmentStore;
+use crate::request_data::{AuthData, HeaderData, RequestData};
+use crate::template;
+
+const SCHEMA_V2_1: &str = "v2.1.0";
+
+#[derive(Debug, Deserialize)]
+struct Collection {
+    info: Info,
+    #[serde(default)]
+    item: Vec<Item>,
+    #[serde(default)]
+    variable: Vec<Variable>,
+    auth: Option<Auth>,
+    #[serde(default)]
+    event: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Info {
+    name: String,
+    #[serde(default)]
+    schema: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Item {
+    #[serde(default)]
+    name: String,
+    item: Option<Vec<Item>>,
+    request: Option<Request>,
+    auth: Option<Auth>,
+    #[serde(default)]
+    event: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Request {
+    Url(String),
+    Full(Box<FullRequest>),
+}
+
+#[derive(Debug, Deserialize)]
+struct FullRequest {
+    method: Option<String>,
+    url: Option<Url>,
+    #[serde(default)]
+    header: Headers,
+    body: Option<Body>,
+    auth: Option<Auth>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Url {
+    Raw(String),
+    Parts(UrlParts),
+}
+
+#[derive(Debug, Deserialize)]
+struct UrlParts {
+    raw: Option<String>,
+    protocol: Option<String>,
+    host: Option<Segments>,
+    port: Option<String>,
+    path: Option<Segments>,
+    #[serde(default)]
+    query: Vec<KeyValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Segments {
+    Joined(String),
+    Split(Vec<Value>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Headers {
+    List(Vec<KeyValue>),
+    // Postman also accepts the raw header block as a single string.
+    Raw(String),
+}
+
+impl Default for Headers {
+    fn default() -> Self {
+        Headers::List(vec![])
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyValue {
+    #[serde(default)]
+    key: String,
+    #[serde(default)]
+    value: Value,
+    #[serde(default)]
+    disabled: bool,
+    #[serde(rename = "type")]
+    kind: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Body {
+    mode: Option<String>,
+    raw: Option<String>,
+    #[serde(default)]
+    urlencoded: Vec<KeyValue>,
+    #[serde(default)]
+    formdata: Vec<KeyValue>,
+    graphql: Option<GraphQl>,
+    options: Option<Value>,
+    #[serde(default)]
+    disabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQl {
+    #[serde(default)]
+    query: String,
+    variables: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Auth {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(flatten)]
+    params: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Variable {
+    #[serde(default)]
+    key: String,
+    #[serde(default)]
+    value: Value,
+    #[serde(default)]
+    disabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct Environment {
+    name: String,
+    #[serde(default)]
+    values: Vec<EnvironmentValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnvironmentValue {
+    key: String,
+    #[serde(default)]
+    value: Value,
+    #[serde(default = "enabled_default")]
+    enabled: bool,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+#[derive(Default)]
+pub struct Report {
+    pub requests: usize,
+    pub environments: Vec<String>,
+    pub skipped: Vec<String>,
+    pub renamed: Vec<String>,
+}
+
+fn value_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+// Postman spells the random UUID helper `$guid`; everything else uses the same `{{name}}` syntax.
+fn convert_variables(text: &str) -> String {
+    text.replace("{{$guid}}", "{{$uuid}}")
+}
+
+struct Importer {
+    store: CollectionStore,
+    used: HashSet<PathBuf>,
+    report: Report,
+}
+
+impl Importer {
+    fn skip(&mut self, location: &Path, what: impl AsRef<str>) {
+        self.report
+            .skipped
+            .push(format!("{}: {}", location.display(), what.as_ref()));
+    }
+
+    fn items(&mut self, items: &[Item], dir: &Path, auth: Option<&Auth>) -> Result<()> {
+        for item in items {
+            let (path, collided) = self.store.unique_path(dir, &item.name, &mut self.used);
+            if !item.event.is_empty() {
+                self.skip(&path, "scripts are not supported");
+            }
+            let auth = item.auth.as_ref().or(auth);
+            match (&item.item, &item.request) {
+                (Some(children), _) => self.items(children, &path, auth)?,
+                (None, Some(request)) => {
+                    if collided {
+                        self.report.renamed.push(path.display().to_string());
+                    }
+                    let request = self.request(request, auth, &path);
+                    self.store.save(
+                        &path,
+                        &SavedRequest {
+                            request,
+                            ..SavedRequest::default()
+                        },
+                    )?;
+                    self.report.requests += 1;
+                }
+                (None, None) => self.skip(&path, "item has neither a request nor children"),
+            }
+        }
+        Ok(())
+    }
+
+    fn request(&mut self, request: &Request, inherited: Option<&Auth>, path: &Path) -> RequestData {
+        let request = match request {
+            Request::Url(url) => {
+                return RequestData {
+                    url: convert_variables(url),
+                    auth: self.auth(inherited, path),
+                    ..RequestData::default()
+                };
+            }
+            Request::Full(request) => request,
+        };
+
+        let mut data = RequestData {
+            method: request
+                .method
+                .clone()
+                .unwrap_or_else(|| "GET".to_string())
+                .to_uppercase(),
+            url: request.url.as_ref().map(url).unwrap_or_default(),
+            ..RequestData::default()
+        };
+        data.headers = match &request.header {
+            Headers::List(headers) => headers
+                .iter()
+                .map(|h| HeaderData {
+                    name: h.key.clone(),
+                    value: convert_variables(&value_string(&h.value)),
+                    enabled: !h.disabled,
+                })
+                .collect(),
+            Headers::Raw(text) => text
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, value)| HeaderData {
+                    name: name.trim().to_string(),
+                    value: convert_variables(value.trim()),
+                    enabled: true,
+                })
+                .collect(),
+        };
+        if let Some(body) = request.body.as_ref().filter(|body| !body.disabled) {
+            self.body(body, &mut data, path);
+        }
+        data.auth = self.auth(request.auth.as_ref().or(inherited), path);
+        data
+    }
+
+    fn body(&mut self, body: &Body, data: &mut RequestData, path: &Path) {
+        let content_type = match body.mode.as_deref() {
+            Some("raw") => {
+                data.body = convert_variables(body.raw.as_deref().unwrap_or_default());
+                let language = body
+                    .options
+                    .as_ref()
+                    .and_then(|options| options.pointer("/raw/language"))
+                    .and_then(|language| language.as_str());
+                match language {
+                    Some("json") => Some("application/json".to_string()),
+                    Some("xml") => Some("application/xml".to_string()),
+                    Some("html") => Some("text/html".to_string()),
+                    _ => None,
+                }
+            }
+            Some("urlencoded") => {
+                data.body = body
+                    .urlencoded
+                    .iter()
+                    .filter(|p| !p.disabled)
+                    .map(|p| {
+                        let encode = |text: &str| {
+                            template::map_literals(&convert_variables(text), urlencode)
+                        };
+                        format!("{}={}", encode(&p.key), encode(&value_string(&p.value)))
+                    })
+                    .collect::<Vec<String>>()
+                    .join("&");
+                Some("application/x-www-form-urlencoded".to_string())
+            }
+            Some("formdata") => {
+                let mut fields = vec![];
+                for field in body.formdata.iter().filter(|f| !f.disabled) {
+                    if field.kind.as_deref() == Some("file") {
+                        self.skip(path, format!("file form field {}", field.key));
+                    } else {
+                        fields.push((
+                            field.key.clone(),
+                            convert_variables(&value_string(&field.value)),
+                        ));
+                    }
+                }
+                data.body = multipart_body(&fields);
+                Some(format!("multipart/form-data; boundary={}", FORM_BOUNDARY))
+            }
+            Some("graphql") => {
+                let graphql = body.graphql.as_ref();
+                let variables = graphql
+                    .and_then(|graphql| graphql.variables.as_deref())
+                    .and_then(|variables| serde_json::from_str::<Value>(variables).ok())
+                    .unwrap_or_else(|| serde_json::json!({}));
+                data.body = serde_json::to_string_pretty(&serde_json::json!({
+                    "query": graphql.map(|graphql| graphql.query.as_str()).unwrap_or_default(),
+                    "variables": variables,
+                }))
+                .unwrap_or_default();
+                Some("application/json".to_string())
+            }
+            Some(mode) => {
+                self.skip(path, format!("{} body", mode));
+                None
+            }
+            None => None,
+        };
+
+        if let Some(content_type) = content_type {
+            if !data
+                .headers
+                .iter()
+                .any(|h| h.name.eq_ignore_ascii_case("Content-Type"))
+            {
+                data.headers.push(HeaderData {
+                    name: "Content-Type".to_string(),
+                    value: content_type,
+                    enabled: true,
+                });
+            }
+        }
+    }
+
+    fn auth(&mut self, auth: Option<&Auth>, path: &Path) -> AuthData {
+        let auth = match auth {
+            Some(auth) => auth,
+            None => return AuthData::None,
+        };
+        let param = |name: &str| {
+            let params = auth.params.get(&auth.kind);
+            let value = match params {
+                // v2.1 stores parameters as a list of key/value pairs.
+                Some(Value::Array(pairs)) => pairs
+                    .iter()
+                    .find(|pair| pair.get("key").and_then(|key| key.as_str()) == Some(name))
+                    .and_then(|pair| pair.get("value")),
+                // v2.0 stores them as a plain object.
+                Some(Value::Object(map)) => map.get(name),
+                _ => None,
+            };
+            convert_variables(&value.map(value_string).unwrap_or_default())
+        };
+        match auth.kind.as_str() {
+            "noauth" => AuthData::None,
+            "basic" => AuthData::Basic {
+                username: param("username"),
+                password: param("password"),
+            },
+            "bearer" => AuthData::Bearer {
+                token: param("token"),
+            },
+            "apikey" => {
+                let (key, value) = (param("key"), param("value"));
+                if param("in") == "query" {
+                    AuthData::ApiKeyQuery { key, value }
+                } else {
+                    AuthData::ApiKeyHeader { key, value }
+                }
+            }
+            kind => {
+                self.skip(path, format!("{} auth", kind));
+                AuthData::None
+            }
+        }
+    }
+}
+
+fn url(url: &Url) -> String {
+    let parts = match url {
+        Url::Raw(raw) => return convert_variables(raw),
+        Url::Parts(parts) => parts,
+    };
+    if let Some(raw) = &parts.raw {
+        return convert_variables(raw);
+    }
+
+    let join = |segments: &Option<Segments>, separator: &str| match segments {
+        Some(Segments::Joined(text)) => text.clone(),
+        Some(Segments::Split(segments)) => segments
+            .iter()
+            .map(value_string)
+            .collect::<Vec<String>>()
+            .join(separator),
+        None => String::new(),
+    };
+    let mut url = String::new();
+    if let Some(protocol) = &parts.protocol {
+        url.push_str(&format!("{}://", protocol));
+    }
+    url.push_str(&join(&parts.host, "."));
+    if let Some(port) = &parts.port {
+        url.push_str(&format!(":{}", port));
+    }
+    let path = join(&parts.path, "/");
+    if !path.is_empty() {
+        url.push('/');
+        url.push_str(path.trim_start_matches('/'));
+    }
+    let query = parts
+        .query
+        .iter()
+        .filter(|q| !q.disabled)
+        .map(|q| match &q.value {
+            Value::Null => q.key.clone(),
+            value => format!("{}={}", q.key, value_string(value)),
+        })
+        .collect::<Vec<String>>();
+    if !query.is_empty() {
+        url.push('?');
+        url.push_str(&query.join("&"));
+    }
+    convert_variables(&url)
+}
+
+fn environment_name(store: &EnvironmentStore, name: &str) -> String {
+    match store.variables_of(name) {
+        Some(_) => format!("{} (merged into the existing environment)", name),
+        None => name.to_string(),
+    }
+}
+
+pub fn import(path: &Path, environments: &[PathBuf], folder: Option<&Path>) -> Result<Report> {
+    let collection: Collection = serde_json::from_str(&fs::read_to_string(path)?)
+        .map_err(|err| anyhow!("{} is not a Postman collection: {}", path.display(), err))?;
+
+    let mut importer = Importer {
+        store: CollectionStore::default(),
+        used: HashSet::new(),
+        report: Report::default(),
+    };
+    if !collection.info.schema.is_empty() && !collection.info.schema.contains(SCHEMA_V2_1) {
+        importer.report.skipped.push(format!(
+            "schema {} is not v2.1, some fields may be missing",
+            collection.info.schema
+        ));
+    }
+    if !collection.event.is_empty() {
+        importer
+            .report
+            .skipped
+            .push("collection scripts are not supported".to_string());
+    }
+
+    let root = folder
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from(collection::file_name(&collection.info.name)));
+    importer.items(&collection.item, &root, collection.auth.as_ref())?;
+
+    // wica has no collection-scoped variables, so they become an environment of their own and
+    // the base of every imported environment, which Postman lets override them.
+    let collection_vars = collection
+        .variable
+        .iter()
+        .filter(|v| !v.disabled)
+        .map(|v| (v.key.clone(), convert_variables(&value_string(&v.value))))
+        .collect::<BTreeMap<String, String>>();
+    let mut store = EnvironmentStore::load()?;
+    if !collection_vars.is_empty() {
+        let name = environment_name(&store, &collection.info.name);
+        store.set_variables(&collection.info.name, collection_vars.clone())?;
+        importer.report.environments.push(name);
+    }
+    for path in environments {
+        let environment: Environment = serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|err| anyhow!("{} is not a Postman environment: {}", path.display(), err))?;
+        let mut vars = collection_vars.clone();
+        vars.extend(
+            environment
+                .values
+                .iter()
+                .filter(|v| v.enabled)
+                .map(|v| (v.key.clone(), convert_variables(&value_string(&v.value)))),
+        );
+        let name = environment_name(&store, &environment.name);
+        store.set_variables(&environment.name, vars)?;
+        importer.report.environments.push(name);
+    }
+
+    Ok(importer.report)
+}
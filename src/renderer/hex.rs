@@ -0,0 +1,31 @@
+pub const BYTES_PER_LINE: usize = 16;
+
+pub fn dump(raw: &[u8]) -> Vec<String> {
+    raw.chunks(BYTES_PER_LINE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let hex = chunk
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<String>>()
+                .join(" ");
+            let ascii = chunk
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            format!(
+                "{:08x}  {:<width$}  |{}|",
+                i * BYTES_PER_LINE,
+                hex,
+                ascii,
+                width = BYTES_PER_LINE * 3 - 1
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,3 @@
+pub fn pretty_print(text: &str) -> Result<String, String> {
+    jsonxf::pretty_print(text)
+}
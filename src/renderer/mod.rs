@@ -0,0 +1,121 @@
+mod hex;
+mod html;
+mod json;
+mod xml;
+
+pub use hex::BYTES_PER_LINE as HEX_BYTES_PER_LINE;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BodyKind {
+    Empty,
+    Json,
+    Xml,
+    Html,
+    Text,
+    Binary,
+}
+
+impl BodyKind {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            BodyKind::Empty => "EMPTY",
+            BodyKind::Json => "JSON",
+            BodyKind::Xml => "XML",
+            BodyKind::Html => "HTML",
+            BodyKind::Text => "TEXT",
+            BodyKind::Binary => "BINARY",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            BodyKind::Empty | BodyKind::Text => "txt",
+            BodyKind::Json => "json",
+            BodyKind::Xml => "xml",
+            BodyKind::Html => "html",
+            BodyKind::Binary => "bin",
+        }
+    }
+
+    pub fn from_content_type(content_type: Option<&str>, raw: &[u8]) -> BodyKind {
+        if raw.is_empty() {
+            return BodyKind::Empty;
+        }
+        if is_binary(raw) {
+            return BodyKind::Binary;
+        }
+
+        let essence = content_type
+            .and_then(|c| c.split(';').next())
+            .map(|c| c.trim().to_lowercase());
+        match essence.as_deref() {
+            Some(c) if c.ends_with("json") || c.ends_with("+json") => BodyKind::Json,
+            Some(c) if c.ends_with("/xml") || c.ends_with("+xml") => BodyKind::Xml,
+            Some("text/html") => BodyKind::Html,
+            Some(c)
+                if c.starts_with("text/")
+                    || c == "application/javascript"
+                    || c == "application/x-www-form-urlencoded" =>
+            {
+                BodyKind::Text
+            }
+            Some(c)
+                if c.starts_with("image/")
+                    || c.starts_with("audio/")
+                    || c.starts_with("video/")
+                    || c.starts_with("font/")
+                    || c == "application/octet-stream"
+                    || c == "application/pdf"
+                    || c == "application/zip"
+                    || c == "application/gzip"
+                    || c.contains("protobuf") =>
+            {
+                BodyKind::Binary
+            }
+            _ => match std::str::from_utf8(raw) {
+                Ok(text) if json::pretty_print(text).is_ok() => BodyKind::Json,
+                Ok(_) => BodyKind::Text,
+                Err(_) => BodyKind::Binary,
+            },
+        }
+    }
+}
+
+const SNIFF_LEN: usize = 8192;
+
+// Treats the payload as binary when it is not UTF-8 or is dominated by control characters,
+// regardless of what the server claims in Content-Type.
+pub fn is_binary(raw: &[u8]) -> bool {
+    let sample = &raw[..raw.len().min(SNIFF_LEN)];
+    let text = match std::str::from_utf8(sample) {
+        Ok(text) => text,
+        // The sample may end in the middle of a multi-byte character.
+        Err(e) if e.error_len().is_none() => {
+            std::str::from_utf8(&sample[..e.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return true,
+    };
+    let controls = text
+        .chars()
+        .filter(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t' | '\x0c'))
+        .count();
+    text.contains('\0') || controls * 10 > text.chars().count()
+}
+
+pub fn render(kind: BodyKind, raw: &[u8]) -> Vec<String> {
+    let text = String::from_utf8_lossy(raw);
+    let rendered = match kind {
+        BodyKind::Empty => return vec![],
+        BodyKind::Json => json::pretty_print(&text),
+        BodyKind::Xml => xml::pretty_print(&text),
+        BodyKind::Html => Ok(html::to_text(&text)),
+        BodyKind::Text => Ok(text.to_string()),
+        BodyKind::Binary => return hex::dump(raw),
+    };
+
+    rendered
+        .unwrap_or_else(|_| text.to_string())
+        .lines()
+        .map(|s| s.to_string())
+        .collect()
+}
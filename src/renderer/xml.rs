@@ -0,0 +1,66 @@
+const INDENT: &str = "  ";
+
+pub fn pretty_print(text: &str) -> Result<String, String> {
+    let mut lines = vec![];
+    let mut depth: usize = 0;
+    let mut rest = text.trim();
+
+    while !rest.is_empty() {
+        if rest.starts_with('<') {
+            let end = tag_end(rest).ok_or_else(|| "unterminated tag".to_string())?;
+            let tag = &rest[..end];
+            rest = rest[end..].trim_start();
+
+            if tag.starts_with("</") {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| format!("unexpected closing tag {}", tag))?;
+                lines.push(format!("{}{}", INDENT.repeat(depth), tag));
+            } else if tag.starts_with("<?") || tag.starts_with("<!") || tag.ends_with("/>") {
+                lines.push(format!("{}{}", INDENT.repeat(depth), tag));
+            } else {
+                // Keep `<a>text</a>` on a single line.
+                let text_end = rest.find('<').unwrap_or(rest.len());
+                let inner = rest[..text_end].trim();
+                let after = &rest[text_end..];
+                if after.starts_with("</") && !inner.contains('\n') {
+                    let close_end = tag_end(after).ok_or_else(|| "unterminated tag".to_string())?;
+                    lines.push(format!(
+                        "{}{}{}{}",
+                        INDENT.repeat(depth),
+                        tag,
+                        inner,
+                        &after[..close_end]
+                    ));
+                    rest = after[close_end..].trim_start();
+                } else {
+                    lines.push(format!("{}{}", INDENT.repeat(depth), tag));
+                    depth += 1;
+                }
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let content = rest[..end].trim();
+            if !content.is_empty() {
+                lines.push(format!("{}{}", INDENT.repeat(depth), content));
+            }
+            rest = &rest[end..];
+        }
+    }
+
+    if depth != 0 {
+        return Err("unclosed tag".to_string());
+    }
+    Ok(lines.join("\n"))
+}
+
+fn tag_end(text: &str) -> Option<usize> {
+    let terminator = if text.starts_with("<!--") {
+        "-->"
+    } else if text.starts_with("<![CDATA[") {
+        "]]>"
+    } else {
+        ">"
+    };
+    text.find(terminator).map(|i| i + terminator.len())
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestData {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<HeaderData>,
+    #[serde(default)]
+    pub auth: AuthData,
+    #[serde(default)]
+    pub body: String,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub insecure: bool,
+}
+
+impl Default for RequestData {
+    fn default() -> Self {
+        Self {
+            method: "GET".to_string(),
+            url: String::new(),
+            headers: vec![],
+            auth: AuthData::None,
+            body: String::new(),
+            insecure: false,
+        }
+    }
+}
+
+impl RequestData {
+    pub fn query(&self) -> &str {
+        self.url.split_once('?').map(|(_, q)| q).unwrap_or("")
+    }
+
+    pub fn enabled_headers(&self) -> impl Iterator<Item = &HeaderData> {
+        self.headers.iter().filter(|h| h.enabled)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeaderData {
+    pub name: String,
+    pub value: String,
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthData {
+    #[default]
+    None,
+    Basic {
+        username: String,
+        password: String,
+    },
+    Bearer {
+        token: String,
+    },
+    ApiKeyHeader {
+        key: String,
+        value: String,
+    },
+    ApiKeyQuery {
+        key: String,
+        value: String,
+    },
+}
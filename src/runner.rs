@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::assertion;
+use crate::collection::CollectionStore;
+use crate::environment::EnvironmentStore;
+use crate::http_request;
+use crate::template;
+
+pub async fn run(target: &Path, env: Option<&str>) -> Result<bool> {
+    let environments = EnvironmentStore::load()?;
+    let mut variables = match env {
+        Some(name) => environments
+            .variables_of(name)
+            .ok_or_else(|| anyhow!("no environment named {}", name))?,
+        None => environments.variables(),
+    };
+
+    let store = CollectionStore::default();
+    let paths = store.resolve(target)?;
+    if paths.is_empty() {
+        return Err(anyhow!("{} contains no requests", target.display()));
+    }
+
+    let mut failed = 0;
+    for path in &paths {
+        let saved = store.load(path)?;
+        let data = template::render_request(&saved.request, &variables);
+        println!("==> {}  {} {}", path.display(), data.method, data.url);
+
+        let resp = match http_request::request(&data).await {
+            Ok(resp) => resp,
+            Err(err) => {
+                eprintln!("{}", err);
+                failed += 1;
+                continue;
+            }
+        };
+        println!("{}  {}ms", resp.status, resp.delay.as_millis());
+        for line in &resp.body {
+            println!("{}", line);
+        }
+
+        for capture in &saved.captures {
+            match capture.extract(&resp) {
+                Ok(value) => {
+                    variables.insert(capture.name.clone(), value);
+                }
+                Err(err) => eprintln!("capture {} failed: {}", capture.name, err),
+            }
+        }
+
+        let results = assertion::evaluate_all(&saved.assertions, &resp);
+        for result in &results {
+            if result.passed {
+                println!("PASS {}", result.description);
+            } else {
+                eprintln!(
+                    "FAIL {}  ({})",
+                    result.description,
+                    result.message.as_deref().unwrap_or_default()
+                );
+            }
+        }
+        if results.iter().any(|result| !result.passed) {
+            failed += 1;
+        }
+        println!();
+    }
+
+    println!(
+        "{} request(s), {} passed, {} failed",
+        paths.len(),
+        paths.len() - failed,
+        failed
+    );
+    Ok(failed == 0)
+}
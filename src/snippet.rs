@@ -0,0 +1,212 @@
+use crate::http_request;
+use crate::request_data::RequestData;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Curl,
+    Reqwest,
+    Python,
+    Fetch,
+    Httpie,
+}
+
+pub const FORMATS: [Format; 5] = [
+    Format::Curl,
+    Format::Reqwest,
+    Format::Python,
+    Format::Fetch,
+    Format::Httpie,
+];
+
+impl Format {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Format::Curl => "curl",
+            Format::Reqwest => "Rust reqwest",
+            Format::Python => "Python requests",
+            Format::Fetch => "JavaScript fetch",
+            Format::Httpie => "HTTPie",
+        }
+    }
+}
+
+struct Parts {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    insecure: bool,
+}
+
+impl Parts {
+    // Going through `http_request::build` keeps auth and query handling identical to what is sent.
+    fn new(data: &RequestData) -> Result<Self, http_request::Error> {
+        let req = http_request::build(data)?;
+        Ok(Self {
+            method: req.method().to_string(),
+            url: req.url().to_string(),
+            headers: req
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        String::from_utf8_lossy(value.as_bytes()).to_string(),
+                    )
+                })
+                .collect(),
+            body: Some(data.body.clone()).filter(|body| !body.is_empty()),
+            insecure: data.insecure,
+        })
+    }
+}
+
+pub fn render(format: Format, data: &RequestData) -> Result<String, http_request::Error> {
+    let parts = Parts::new(data)?;
+    Ok(match format {
+        Format::Curl => curl(&parts),
+        Format::Reqwest => reqwest(&parts),
+        Format::Python => python(&parts),
+        Format::Fetch => fetch(&parts),
+        Format::Httpie => httpie(&parts),
+    })
+}
+
+fn shell_quote(text: &str) -> String {
+    if !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@%+,".contains(c))
+    {
+        text.to_string()
+    } else {
+        format!("'{}'", text.replace('\'', "'\\''"))
+    }
+}
+
+fn curl(parts: &Parts) -> String {
+    let mut args = vec![];
+    match (parts.method.as_str(), &parts.body) {
+        ("GET", None) => {}
+        ("HEAD", None) => args.push("--head".to_string()),
+        ("POST", Some(_)) => {}
+        (method, _) => args.push(format!("-X {}", shell_quote(method))),
+    }
+    if parts.insecure {
+        args.push("-k".to_string());
+    }
+    args.push(shell_quote(&parts.url));
+    for (name, value) in &parts.headers {
+        args.push(format!(
+            "-H {}",
+            shell_quote(&format!("{}: {}", name, value))
+        ));
+    }
+    if let Some(body) = &parts.body {
+        args.push(format!("--data-raw {}", shell_quote(body)));
+    }
+    format!("curl {}", args.join(" \\\n  "))
+}
+
+fn httpie(parts: &Parts) -> String {
+    let mut args = vec![];
+    if parts.insecure {
+        args.push("--verify=no".to_string());
+    }
+    args.push(format!(
+        "{} {}",
+        shell_quote(&parts.method),
+        shell_quote(&parts.url)
+    ));
+    for (name, value) in &parts.headers {
+        args.push(shell_quote(&format!("{}:{}", name, value)));
+    }
+    if let Some(body) = &parts.body {
+        args.push(format!("--raw {}", shell_quote(body)));
+    }
+    format!("http {}", args.join(" \\\n  "))
+}
+
+fn reqwest(parts: &Parts) -> String {
+    let mut lines = vec![];
+    if parts.insecure {
+        lines.push("let client = reqwest::Client::builder()".to_string());
+        lines.push("    .danger_accept_invalid_certs(true)".to_string());
+        lines.push("    .build()?;".to_string());
+    } else {
+        lines.push("let client = reqwest::Client::new();".to_string());
+    }
+    lines.push("let response = client".to_string());
+    match parts.method.as_str() {
+        "GET" | "POST" | "PUT" | "PATCH" | "DELETE" | "HEAD" => lines.push(format!(
+            "    .{}({:?})",
+            parts.method.to_lowercase(),
+            parts.url
+        )),
+        method => lines.push(format!(
+            "    .request(reqwest::Method::from_bytes(b{:?})?, {:?})",
+            method, parts.url
+        )),
+    }
+    for (name, value) in &parts.headers {
+        lines.push(format!("    .header({:?}, {:?})", name, value));
+    }
+    if let Some(body) = &parts.body {
+        lines.push(format!("    .body({:?})", body));
+    }
+    lines.push("    .send()".to_string());
+    lines.push("    .await?;".to_string());
+    lines.push("println!(\"{}\", response.text().await?);".to_string());
+    lines.join("\n")
+}
+
+// JSON string literals are also valid Python and JavaScript string literals.
+fn quote(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_default()
+}
+
+fn python(parts: &Parts) -> String {
+    let mut lines = vec![
+        "import requests".to_string(),
+        String::new(),
+        "response = requests.request(".to_string(),
+        format!("    {},", quote(&parts.method)),
+        format!("    {},", quote(&parts.url)),
+    ];
+    if !parts.headers.is_empty() {
+        lines.push("    headers={".to_string());
+        for (name, value) in &parts.headers {
+            lines.push(format!("        {}: {},", quote(name), quote(value)));
+        }
+        lines.push("    },".to_string());
+    }
+    if let Some(body) = &parts.body {
+        lines.push(format!("    data={},", quote(body)));
+    }
+    if parts.insecure {
+        lines.push("    verify=False,".to_string());
+    }
+    lines.push(")".to_string());
+    lines.push("print(response.text)".to_string());
+    lines.join("\n")
+}
+
+fn fetch(parts: &Parts) -> String {
+    let mut lines = vec![
+        format!("const response = await fetch({}, {{", quote(&parts.url)),
+        format!("  method: {},", quote(&parts.method)),
+    ];
+    if !parts.headers.is_empty() {
+        lines.push("  headers: {".to_string());
+        for (name, value) in &parts.headers {
+            lines.push(format!("    {}: {},", quote(name), quote(value)));
+        }
+        lines.push("  },".to_string());
+    }
+    if let Some(body) = &parts.body {
+        lines.push(format!("  body: {},", quote(body)));
+    }
+    lines.push("});".to_string());
+    lines.push("console.log(await response.text());".to_string());
+    lines.join("\n")
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::env;
+
+use chrono::{SecondsFormat, Utc};
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::request_data::{AuthData, HeaderData, RequestData};
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+const DEFAULT_RANDOM_INT_RANGE: (i64, i64) = (0, 1000);
+
+// Replaces every `{{name}}` with its value, leaving unknown references untouched.
+// References may be nested, e.g. `{{$base64({{user}}:{{password}})}}`.
+pub fn render(text: &str, vars: &HashMap<String, String>) -> String {
+    let mut rendered = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(OPEN) {
+        rendered.push_str(&rest[..start]);
+        let after_open = &rest[start + OPEN.len()..];
+        match matching_close(after_open) {
+            Some(end) => {
+                let name = render(&after_open[..end], vars);
+                let name = name.trim();
+                match vars.get(name).cloned().or_else(|| dynamic(name)) {
+                    Some(value) => rendered.push_str(&value),
+                    None => rendered.push_str(&rest[start..start + OPEN.len() + end + CLOSE.len()]),
+                }
+                rest = &after_open[end + CLOSE.len()..];
+            }
+            None => {
+                rendered.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+// Applies `f` to the text outside of `{{...}}` references, e.g. to encode it while leaving the
+// references for `render` to substitute.
+pub fn map_literals(text: &str, f: impl Fn(&str) -> String) -> String {
+    let mut mapped = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(OPEN) {
+        let after_open = &rest[start + OPEN.len()..];
+        match matching_close(after_open) {
+            Some(end) => {
+                let reference_end = start + OPEN.len() + end + CLOSE.len();
+                mapped.push_str(&f(&rest[..start]));
+                mapped.push_str(&rest[start..reference_end]);
+                rest = &rest[reference_end..];
+            }
+            None => break,
+        }
+    }
+    mapped.push_str(&f(rest));
+    mapped
+}
+
+fn matching_close(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = 0;
+    while i < text.len() {
+        if text[i..].starts_with(OPEN) {
+            depth += 1;
+            i += OPEN.len();
+        } else if text[i..].starts_with(CLOSE) {
+            if depth == 0 {
+                return Some(i);
+            }
+            depth -= 1;
+            i += CLOSE.len();
+        } else {
+            i += text[i..].chars().next().map_or(1, |c| c.len_utf8());
+        }
+    }
+    None
+}
+
+fn dynamic(name: &str) -> Option<String> {
+    let (function, args) = match name.find('(') {
+        Some(i) if name.ends_with(')') => (&name[..i], Some(&name[i + 1..name.len() - 1])),
+        _ => (name, None),
+    };
+
+    match (function, args) {
+        ("$uuid", None) => Some(Uuid::new_v4().to_string()),
+        ("$timestamp", None) => Some(Utc::now().timestamp().to_string()),
+        ("$isoTimestamp", None) => Some(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+        ("$randomInt", args) => {
+            let (min, max) = match args {
+                None => DEFAULT_RANDOM_INT_RANGE,
+                Some(args) => {
+                    let (min, max) = args.split_once(',')?;
+                    (min.trim().parse().ok()?, max.trim().parse().ok()?)
+                }
+            };
+            if min > max {
+                return None;
+            }
+            Some(rand::thread_rng().gen_range(min..=max).to_string())
+        }
+        ("$base64", Some(text)) => Some(base64::encode(text)),
+        ("$env", Some(key)) => env::var(key.trim()).ok(),
+        _ => None,
+    }
+}
+
+pub fn render_request(data: &RequestData, vars: &HashMap<String, String>) -> RequestData {
+    let r = |text: &String| render(text, vars);
+    RequestData {
+        method: data.method.clone(),
+        url: r(&data.url),
+        headers: data
+            .headers
+            .iter()
+            .map(|h| HeaderData {
+                name: r(&h.name),
+                value: r(&h.value),
+                enabled: h.enabled,
+            })
+            .collect(),
+        auth: match &data.auth {
+            AuthData::None => AuthData::None,
+            AuthData::Basic { username, password } => AuthData::Basic {
+                username: r(username),
+                password: r(password),
+            },
+            AuthData::Bearer { token } => AuthData::Bearer { token: r(token) },
+            AuthData::ApiKeyHeader { key, value } => AuthData::ApiKeyHeader {
+                key: r(key),
+                value: r(value),
+            },
+            AuthData::ApiKeyQuery { key, value } => AuthData::ApiKeyQuery {
+                key: r(key),
+                value: r(value),
+            },
+        },
+        body: r(&data.body),
+        insecure: data.insecure,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn render_replaces_known_variables() {
+        let vars = vars(&[("host", "example.com"), ("id", "42")]);
+        assert_eq!(
+            render("https://{{host}}/users/{{ id }}", &vars),
+            "https://example.com/users/42"
+        );
+    }
+
+    #[test]
+    fn render_keeps_unknown_and_unclosed_references() {
+        let vars = vars(&[("host", "example.com")]);
+        assert_eq!(
+            render("{{missing}}/{{host}}", &vars),
+            "{{missing}}/example.com"
+        );
+        assert_eq!(render("{{host}} {{host", &vars), "example.com {{host");
+    }
+
+    #[test]
+    fn render_resolves_nested_references() {
+        let vars = vars(&[
+            ("user", "alice"),
+            ("password", "secret"),
+            ("key", "host"),
+            ("host", "h"),
+        ]);
+        assert_eq!(
+            render("{{$base64({{user}}:{{password}})}}", &vars),
+            base64::encode("alice:secret")
+        );
+        assert_eq!(render("{{{{key}}}}", &vars), "h");
+    }
+
+    #[test]
+    fn render_dynamic_variables() {
+        let vars = HashMap::new();
+        assert!(Uuid::parse_str(&render("{{$uuid}}", &vars)).is_ok());
+        assert!(render("{{$timestamp}}", &vars).parse::<i64>().is_ok());
+        let n = render("{{$randomInt(5, 7)}}", &vars)
+            .parse::<i64>()
+            .unwrap();
+        assert!((5..=7).contains(&n));
+        assert_eq!(
+            render("{{$randomInt(7, 5)}}", &vars),
+            "{{$randomInt(7, 5)}}"
+        );
+        assert_eq!(render("{{$unknown}}", &vars), "{{$unknown}}");
+    }
+}
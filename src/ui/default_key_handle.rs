@@ -4,34 +4,78 @@ use termion::event::Key;
 use crate::{
     components::{response, ComponentPosition},
     event::{Event, EventSender},
+    keymap::{self, Action},
 };
 
 pub fn default_key_handle(k: Key, event_sender: EventSender) -> Result<()> {
-    match k {
-        Key::Ctrl('c') => event_sender.send(Event::Quit),
-        Key::Ctrl('s') => event_sender.send(Event::Request),
-        Key::Char('m') => {
+    let action = match keymap::get().action(k) {
+        Some(action) => action,
+        None => return Ok(()),
+    };
+    match action {
+        Action::Quit => event_sender.send(Event::Quit),
+        Action::SendRequest => event_sender.send(Event::Request),
+        Action::FocusMethod => {
             event_sender.send(Event::ChangeFocus(ComponentPosition::RequestMethod));
         }
-        Key::Char('u') => {
+        Action::FocusUrl => {
             event_sender.send(Event::ChangeFocus(ComponentPosition::RequestUrl));
         }
-        Key::Char('q') => {
+        Action::FocusQuery => {
             event_sender.send(Event::ChangeFocus(ComponentPosition::RequestQuery));
         }
-        Key::Char('r') => {
+        Action::FocusHeaders => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::RequestHeader));
+        }
+        Action::FocusAuth => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::RequestAuth));
+        }
+        Action::FocusBody => {
             event_sender.send(Event::ChangeFocus(ComponentPosition::RequestBody));
         }
-        Key::Char('b') => {
+        Action::Collections => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::Collections));
+        }
+        Action::SwitchEnvironment => event_sender.send(Event::SwitchEnvironment),
+        Action::NewTab => event_sender.send(Event::NewTab),
+        Action::CloseTab => event_sender.send(Event::CloseTab),
+        Action::DuplicateTab => event_sender.send(Event::DuplicateTab),
+        Action::NextTab => event_sender.send(Event::SwitchTab(1)),
+        Action::PreviousTab => event_sender.send(Event::SwitchTab(-1)),
+        Action::History => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::History));
+        }
+        Action::Export => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::Export));
+        }
+        Action::Import => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::Import));
+        }
+        Action::HttpFile => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::HttpFile));
+        }
+        Action::Variables => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::Variables));
+        }
+        Action::Help => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::KeyBindings));
+        }
+        Action::FocusResponseBody => {
             event_sender.send(Event::ChangeFocus(ComponentPosition::Response(
                 response::ResponseComponents::Body,
             )));
         }
-        Key::Char('h') => {
+        Action::FocusResponseHeaders => {
             event_sender.send(Event::ChangeFocus(ComponentPosition::Response(
                 response::ResponseComponents::Header,
             )));
         }
+        Action::FocusTests => {
+            event_sender.send(Event::ChangeFocus(ComponentPosition::Response(
+                response::ResponseComponents::Tests,
+            )));
+        }
+        // Lists, scrollable views and panes handle the rest themselves.
         _ => {}
     }
     Ok(())
@@ -1,3 +1,5 @@
 mod default_key_handle;
+mod popup;
 
 pub use default_key_handle::default_key_handle;
+pub use popup::centered_rect;
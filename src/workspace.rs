@@ -0,0 +1,11 @@
+use std::env;
+use std::path::PathBuf;
+
+const WORKSPACE_ENV: &str = "WICA_WORKSPACE";
+const DEFAULT_WORKSPACE: &str = ".wica";
+
+pub fn dir() -> PathBuf {
+    env::var_os(WORKSPACE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_WORKSPACE))
+}